@@ -0,0 +1,171 @@
+//! Finding the errors and signals mentioned in free-form text such as log lines.
+
+use crate::arch::Arch;
+use crate::errno::ErrnoDesc;
+use crate::os::Os;
+use crate::signal::SignalDesc;
+use libc::c_int;
+
+/// An error or a signal mentioned in a line
+pub struct Mention<T> {
+    /// The byte offset just past the mention, where an annotation fits
+    pub end: usize,
+    /// Whether the line spells the symbolic name itself (e.g. "ENOSPC")
+    pub by_name: bool,
+    pub desc: T,
+}
+
+/// Finds the errors mentioned in the line, numbered like on the system (and the architecture on Linux)
+///
+/// Recognizes `os error 24`, `errno=11`, `Os { code: 2, kind: NotFound }` and symbolic names like `ENOSPC`.
+pub fn errors(os: Os, arch: Arch, line: &str) -> Vec<Mention<ErrnoDesc>> {
+    let from_number = |number| match os {
+        Os::Linux => ErrnoDesc::from_number_on(arch, number),
+        os => ErrnoDesc::from_number_in(os, number),
+    };
+    let mut mentions = Vec::new();
+    for (end, number) in numbers_after(line, "os error")
+        .into_iter()
+        .chain(numbers_after(line, "errno"))
+    {
+        if let Some(desc) = from_number(number) {
+            mentions.push(Mention::number(end, desc));
+        }
+    }
+    for (end, number) in numbers_after(line, "os { code") {
+        // annotate the whole debug representation, after its `kind`
+        let end = match line[end..].find('}') {
+            Some(brace) => end + brace + 1,
+            None => end,
+        };
+        if let Some(desc) = from_number(number) {
+            mentions.push(Mention::number(end, desc));
+        }
+    }
+    for (end, word) in words(line).filter(|(_, word)| is_symbolic(word, "E")) {
+        if let Some(desc) = ErrnoDesc::from_name_in(os, word).map(|desc| desc.on(arch)) {
+            mentions.push(Mention::name(end, desc));
+        }
+    }
+    sorted(mentions, |desc| desc.name().to_owned())
+}
+
+/// Finds the signals mentioned in the line, numbered like on the system (and the architecture on Linux)
+///
+/// Recognizes `killed by signal 9` (or any `signal` followed by a number), shell statuses
+/// above 128 like `exit status 137`, and symbolic names like `SIGKILL`.
+pub fn signals(os: Os, arch: Arch, line: &str) -> Vec<Mention<SignalDesc>> {
+    let from_number = |number| match os {
+        Os::Linux => SignalDesc::from_number_on(arch, number),
+        os => SignalDesc::from_number_in(os, number),
+    };
+    let mut mentions = Vec::new();
+    for (end, number) in numbers_after(line, "signal") {
+        if let Some(desc) = from_number(number) {
+            mentions.push(Mention::number(end, desc));
+        }
+    }
+    for (end, status) in numbers_after(line, "exit status")
+        .into_iter()
+        .chain(numbers_after(line, "exit code"))
+        .filter(|(_, status)| *status > 128)
+    {
+        if let Some(desc) = from_number(status - 128) {
+            mentions.push(Mention::number(end, desc));
+        }
+    }
+    for (end, word) in words(line).filter(|(_, word)| is_symbolic(word, "SIG")) {
+        let desc = match os {
+            Os::Linux => SignalDesc::from_name_on(arch, word),
+            os => SignalDesc::from_name_in(os, word),
+        };
+        if let Some(desc) = desc {
+            mentions.push(Mention::name(end, desc));
+        }
+    }
+    sorted(mentions, |desc| desc.name().to_owned())
+}
+
+impl<T> Mention<T> {
+    fn number(end: usize, desc: T) -> Self {
+        Self {
+            end,
+            by_name: false,
+            desc,
+        }
+    }
+
+    fn name(end: usize, desc: T) -> Self {
+        Self {
+            end,
+            by_name: true,
+            desc,
+        }
+    }
+}
+
+/// Orders the mentions along the line, keeping only the first one of each name
+fn sorted<T>(mut mentions: Vec<Mention<T>>, name: impl Fn(&T) -> String) -> Vec<Mention<T>> {
+    mentions.sort_by_key(|mention| mention.end);
+    let mut seen = Vec::new();
+    mentions.retain(|mention| {
+        let name = name(&mention.desc);
+        let first = !seen.contains(&name);
+        seen.push(name);
+        first
+    });
+    mentions
+}
+
+/// Returns the end and the value of the numbers following the keyword,
+/// which is matched case-insensitively on word boundaries (e.g. "errno=11" or "Errno: 11")
+fn numbers_after(line: &str, keyword: &str) -> Vec<(usize, c_int)> {
+    let lower = line.to_ascii_lowercase();
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    for (start, _) in lower.match_indices(keyword) {
+        if start > 0 && is_word_byte(bytes[start - 1]) {
+            continue;
+        }
+        let mut pos = start + keyword.len();
+        while pos < bytes.len() && matches!(bytes[pos], b' ' | b':' | b'=' | b'#') {
+            pos += 1;
+        }
+        let digits = bytes[pos..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        let end = pos + digits;
+        if digits == 0 || (end < bytes.len() && is_word_byte(bytes[end])) {
+            continue;
+        }
+        if let Ok(number) = line[pos..end].parse() {
+            found.push((end, number));
+        }
+    }
+    found
+}
+
+/// Returns the words of the line with their end
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map(move |word| {
+            (
+                word.as_ptr() as usize - line.as_ptr() as usize + word.len(),
+                word,
+            )
+        })
+}
+
+/// Whether the word looks like a symbolic name with the prefix (e.g. "ENOSPC" for "E")
+fn is_symbolic(word: &str, prefix: &str) -> bool {
+    word.len() > prefix.len()
+        && word.starts_with(prefix)
+        && word
+            .bytes()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
@@ -0,0 +1,49 @@
+//! Linux architectures whose signal and error numbers differ from the generic ones.
+
+/// A family of Linux architectures sharing the same signal and error numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Arch {
+    /// x86, ARM, RISC-V and most others
+    #[cfg_attr(
+        feature = "cli",
+        value(alias = "x86", alias = "x86_64", alias = "i386", alias = "arm")
+    )]
+    #[cfg_attr(
+        feature = "cli",
+        value(alias = "aarch64", alias = "arm64", alias = "riscv64", alias = "s390x")
+    )]
+    Generic,
+    Alpha,
+    #[cfg_attr(feature = "cli", value(alias = "mips64", alias = "mipsel"))]
+    Mips,
+    #[cfg_attr(
+        feature = "cli",
+        value(alias = "ppc", alias = "ppc64", alias = "powerpc64")
+    )]
+    Powerpc,
+    #[cfg_attr(feature = "cli", value(alias = "sparc64"))]
+    Sparc,
+}
+
+impl Arch {
+    /// All the architecture families, the generic one first
+    pub const ALL: [Arch; 5] = [
+        Arch::Generic,
+        Arch::Alpha,
+        Arch::Mips,
+        Arch::Powerpc,
+        Arch::Sparc,
+    ];
+
+    /// Returns the lowercase name (e.g. "mips")
+    pub fn name(self) -> &'static str {
+        match self {
+            Arch::Generic => "generic",
+            Arch::Alpha => "alpha",
+            Arch::Mips => "mips",
+            Arch::Powerpc => "powerpc",
+            Arch::Sparc => "sparc",
+        }
+    }
+}
@@ -1,133 +1,32 @@
-use box_drawing_table::{ansi_term::Style, Align, Border, Cell, Column, Row, Table};
-use clap::Parser;
-
-/// A simple utility to look up ASCII code
-#[derive(Debug, Parser)]
-#[clap(author, version, about, long_about = None)]
-struct Args {
-    #[clap(value_parser)]
-    /// single character (e.g. "A"), ASCII number (e.g. "65", "0o101", "0x41"), or caret notation (e.g. "^@")
-    query: Vec<String>,
-
-    #[clap(long, default_value_t = false)]
-    /// Disable pretty-printing
-    simple: bool,
-
-    #[clap(short, long, default_value_t = false)]
-    /// List all signals
-    list: bool,
-
-    #[clap(short, long, default_value_t = false)]
-    /// Look up ASCII digits
-    digit: bool,
+/// Returns all ASCII characters in ascending order
+pub fn list() -> impl Iterator<Item = char> {
+    '\x00'..='\x7f'
 }
 
-fn main() {
-    let args = Args::parse();
-
-    let mut rows = Vec::new();
-
-    let queries: Vec<String> = if args.list {
-        ('\x00'..='\x7f')
-            .into_iter()
-            .map(|ch| ch.to_string())
-            .collect()
-    } else {
-        args.query
-    };
-
-    for q in queries {
-        let ch;
-        if q.starts_with("0x") || q.starts_with("0b") || q.starts_with("0o") {
-            ch = number_to_char(&q);
-        } else if q.len() == 2 && q.starts_with('^') {
-            ch = caret_notation(&q);
-        } else if !q.is_empty() && q.is_ascii() {
-            let first_ch = q.chars().next().unwrap();
-            if q.len() >= 2 || (!args.digit && first_ch.is_ascii_digit()) {
-                ch = number_to_char(&q);
-            } else {
-                ch = Some(first_ch);
-            }
-        } else {
-            ch = None;
-        }
-
-        let display;
-        let bin;
-        let oct;
-        let hex;
-        let dec;
-        if let Some(ch) = ch {
-            display = display_repr(ch);
-            bin = format!("0b{:07b}", ch as u8);
-            oct = format!("0o{:03o}", ch as u8);
-            hex = format!("0x{:02X}", ch as u8);
-            dec = format!("{}", ch as u8);
-        } else {
-            display = q.as_str();
-            bin = "-".to_owned();
-            oct = "-".to_owned();
-            hex = "-".to_owned();
-            dec = "-".to_owned();
-        }
-
-        if args.simple {
-            println!("{} {} {} {} {}", display, hex, dec, oct, bin);
+/// Looks up the ASCII character denoted by a query
+///
+/// A query is a single character (e.g. "A"), an ASCII number (e.g. "65", "0o101", "0x41"),
+/// or caret notation (e.g. "^@"). A single digit is interpreted as a number unless `digit`
+/// is `true`.
+pub fn lookup(query: &str, digit: bool) -> Option<char> {
+    if query.starts_with("0x") || query.starts_with("0b") || query.starts_with("0o") {
+        number_to_char(query)
+    } else if query.len() == 2 && query.starts_with('^') {
+        caret_notation(query)
+    } else if !query.is_empty() && query.is_ascii() {
+        let first_ch = query.chars().next().unwrap();
+        if query.len() >= 2 || (!digit && first_ch.is_ascii_digit()) {
+            number_to_char(query)
         } else {
-            rows.push(Row::flexible_height(vec![
-                Cell {
-                    value: display.to_owned(),
-                    align: Align::Left,
-                    style: Style::default().bold(),
-                },
-                Cell::right(hex),
-                Cell::right(dec),
-                Cell::right(oct),
-                Cell::right(bin),
-            ]));
+            Some(first_ch)
         }
+    } else {
+        None
     }
-
-    if !args.simple && !rows.is_empty() {
-        create_table(rows);
-    }
-}
-
-fn create_table(rows: Vec<Row>) {
-    let mut table = Table::new(vec![
-        Border::Double.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Double.into(),
-    ]);
-
-    table.append_row(Border::Double.into());
-    table.append_row(Row::flexible_height(vec![
-        Cell::left("char"),
-        Cell::left("hex"),
-        Cell::left("dec"),
-        Cell::left("oct"),
-        Cell::left("bin"),
-    ]));
-    table.append_row(Border::Single.into());
-
-    for r in rows {
-        table.append_row(r);
-    }
-
-    table.append_row(Border::Double.into());
-    print!("{}", table);
 }
 
-fn number_to_char(number: &str) -> Option<char> {
+/// Converts an ASCII number (e.g. "65", "0o101", "0x41", "0b1000001") to the character
+pub fn number_to_char(number: &str) -> Option<char> {
     let parsed = if let Some(hex) = number.strip_prefix("0x") {
         u8::from_str_radix(hex, 16)
     } else if let Some(oct) = number.strip_prefix("0o") {
@@ -144,15 +43,20 @@ fn number_to_char(number: &str) -> Option<char> {
     }
 }
 
-fn caret_notation(text: &str) -> Option<char> {
-    match text.strip_prefix('^').unwrap().chars().next().unwrap() {
-        ch @ ('@'..='_' | '?') => Some(((ch as u8) ^ 0x40) as char),
+/// Converts caret notation (e.g. "^@") to the control character it stands for
+///
+/// Returns `None` unless the text is a caret followed by one of `@`, `A` to `_`, or `?`.
+pub fn caret_notation(text: &str) -> Option<char> {
+    let mut chars = text.strip_prefix('^')?.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch @ ('@'..='_' | '?')), None) => Some(((ch as u8) ^ 0x40) as char),
         _ => None,
     }
 }
 
-fn display_repr(ascii_char: char) -> &'static str {
-    match ascii_char {
+/// Returns a human-readable representation of an ASCII character, or `None` for other characters
+pub fn display_repr(ascii_char: char) -> Option<&'static str> {
+    let repr = match ascii_char {
         '\x00' => r#"NUL '\0' (null character)"#,
         '\x01' => r#"SOH (start of heading)"#,
         '\x02' => r#"STX (start of text)"#,
@@ -197,7 +101,7 @@ fn display_repr(ascii_char: char) -> &'static str {
         '\x29' => r#")"#,
         '\x2A' => r#"*"#,
         '\x2B' => r#"+"#,
-        '\x2C' => r#"#"#,
+        '\x2C' => r#","#,
         '\x2D' => r#"-"#,
         '\x2E' => r#"."#,
         '\x2F' => r#"/"#,
@@ -281,6 +185,7 @@ fn display_repr(ascii_char: char) -> &'static str {
         '\x7D' => r#"}"#,
         '\x7E' => r#"~"#,
         '\x7F' => r#"DEL"#,
-        _ => unreachable!(),
-    }
+        _ => return None,
+    };
+    Some(repr)
 }
@@ -0,0 +1,6 @@
+use clap::Parser;
+use lookup_utils::cli::ascii::{run, Args};
+
+fn main() {
+    run(Args::parse());
+}
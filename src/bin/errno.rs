@@ -0,0 +1,16 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+use clap::Parser;
+use lookup_utils::cli::errno::{run, Args};
+
+fn main() {
+    run(Args::parse());
+}
@@ -0,0 +1,60 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+use clap::{CommandFactory, Parser, Subcommand};
+use lookup_utils::cli;
+use std::{env, path::Path};
+
+/// A simple utility to look up ASCII code, Linux signals and Linux error numbers
+#[derive(Debug, Parser)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    Ascii(cli::ascii::Args),
+    Signal(cli::signal::Args),
+    Errno(cli::errno::Args),
+    Browse(cli::browse::Args),
+    Strace(cli::strace::Args),
+    Completions(cli::completions::Args),
+    #[clap(hide = true)]
+    Manpages(cli::manpages::Args),
+}
+
+fn main() {
+    // Behave like the standalone commands when invoked through a symlink (e.g. `errno -> lookup`)
+    let command = match invoked_as().as_deref() {
+        Some("ascii") => Command::Ascii(cli::ascii::Args::parse()),
+        Some("signal") => Command::Signal(cli::signal::Args::parse()),
+        Some("errno") => Command::Errno(cli::errno::Args::parse()),
+        _ => Args::parse().command,
+    };
+
+    match command {
+        Command::Ascii(args) => cli::ascii::run(args),
+        Command::Signal(args) => cli::signal::run(args),
+        Command::Errno(args) => cli::errno::run(args),
+        Command::Browse(args) => cli::browse::run(args),
+        Command::Strace(args) => cli::strace::run(args),
+        Command::Completions(args) => cli::completions::run(args, Args::command()),
+        Command::Manpages(args) => cli::manpages::run(args, Args::command()),
+    }
+}
+
+/// Returns the file name this binary was invoked as
+fn invoked_as() -> Option<String> {
+    let argv0 = env::args_os().next()?;
+    let name = Path::new(&argv0).file_stem()?;
+    name.to_str().map(str::to_owned)
+}
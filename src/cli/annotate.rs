@@ -0,0 +1,56 @@
+//! The `--annotate` mode of `errno` and `signal`, also used by `lookup strace`:
+//! explains what the lines of a log mention.
+
+use std::io::{BufRead, Write};
+
+/// Copies the input to the output line by line,
+/// inserting each note returned for a line at its byte offset (the end of the mention)
+///
+/// `bin` prefixes the error message if the input cannot be read (e.g. "errno").
+pub(super) fn run(
+    bin: &str,
+    mut input: impl BufRead,
+    mut output: impl Write,
+    mut notes: impl FnMut(&str) -> Vec<(usize, String)>,
+) {
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match input.read_until(b'\n', &mut buf) {
+            Ok(0) => return,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("{}: cannot read the input: {}", bin, err);
+                std::process::exit(1);
+            }
+        }
+        // lines that are not UTF-8 are passed through without notes
+        let annotated = match std::str::from_utf8(&buf) {
+            Ok(line) => insert(line, &notes(line)).into_bytes(),
+            Err(_) => buf.clone(),
+        };
+        // flush every line so that the output keeps up with `tail -f`;
+        // stop quietly once the reader has gone away
+        if output
+            .write_all(&annotated)
+            .and_then(|_| output.flush())
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+fn insert(line: &str, notes: &[(usize, String)]) -> String {
+    let mut annotated = String::with_capacity(line.len());
+    let mut pos = 0;
+    for (end, note) in notes {
+        annotated.push_str(&line[pos..*end]);
+        annotated.push_str(" [");
+        annotated.push_str(note);
+        annotated.push(']');
+        pos = *end;
+    }
+    annotated.push_str(&line[pos..]);
+    annotated
+}
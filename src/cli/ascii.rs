@@ -0,0 +1,183 @@
+use super::{csv_writer, Format};
+use crate::ascii::{display_repr, list, lookup};
+use box_drawing_table::{ansi_term::Style, Align, Border, Cell, Column, Row, Table};
+use clap::Parser;
+use serde_json::{json, Value};
+
+/// A simple utility to look up ASCII code
+#[derive(Debug, Parser)]
+#[clap(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(value_parser)]
+    /// single character (e.g. "A"), ASCII number (e.g. "65", "0o101", "0x41"), or caret notation (e.g. "^@")
+    query: Vec<String>,
+
+    #[clap(long, default_value_t = false)]
+    /// Disable pretty-printing
+    simple: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["simple", "jsonl"])]
+    /// Print the results as a JSON array
+    json: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with = "simple")]
+    /// Print the results as newline-delimited JSON (one object per line)
+    jsonl: bool,
+
+    #[clap(long, value_enum, conflicts_with_all = ["simple", "json", "jsonl"])]
+    /// Export the results as CSV or TSV with a header row
+    format: Option<Format>,
+
+    #[clap(short, long, default_value_t = false)]
+    /// List all ASCII characters
+    list: bool,
+
+    #[clap(short, long, default_value_t = false)]
+    /// Look up ASCII digits
+    digit: bool,
+}
+
+/// Looks up the queried ASCII characters and prints them in the requested format
+pub fn run(args: Args) {
+    let mut rows = Vec::new();
+    let mut objects = Vec::new();
+    let mut writer = args.format.map(|format| {
+        let mut writer = csv_writer(format);
+        writer
+            .write_record(["char", "hex", "dec", "oct", "bin"])
+            .unwrap();
+        writer
+    });
+
+    // listed characters are not looked up, otherwise digits would be taken as numbers
+    let queries: Vec<(String, Option<char>)> = if args.list {
+        list().map(|ch| (ch.to_string(), Some(ch))).collect()
+    } else {
+        args.query
+            .into_iter()
+            .map(|q| {
+                let ch = lookup(&q, args.digit);
+                (q, ch)
+            })
+            .collect()
+    };
+
+    for (q, ch) in queries {
+        if args.json || args.jsonl {
+            let object = json_object(&q, ch);
+            if args.jsonl {
+                println!("{}", object);
+            } else {
+                objects.push(object);
+            }
+            continue;
+        }
+
+        let display;
+        let bin;
+        let oct;
+        let hex;
+        let dec;
+        if let Some(ch) = ch {
+            display = display_repr(ch).unwrap_or_default();
+            bin = format!("0b{:07b}", ch as u8);
+            oct = format!("0o{:03o}", ch as u8);
+            hex = format!("0x{:02X}", ch as u8);
+            dec = format!("{}", ch as u8);
+        } else {
+            display = q.as_str();
+            bin = "-".to_owned();
+            oct = "-".to_owned();
+            hex = "-".to_owned();
+            dec = "-".to_owned();
+        }
+
+        if let Some(writer) = &mut writer {
+            writer
+                .write_record([display, &hex, &dec, &oct, &bin])
+                .unwrap();
+        } else if args.simple {
+            println!("{} {} {} {} {}", display, hex, dec, oct, bin);
+        } else {
+            rows.push(Row::flexible_height(vec![
+                Cell {
+                    value: display.to_owned(),
+                    align: Align::Left,
+                    style: Style::default().bold(),
+                },
+                Cell::right(hex),
+                Cell::right(dec),
+                Cell::right(oct),
+                Cell::right(bin),
+            ]));
+        }
+    }
+
+    if let Some(mut writer) = writer {
+        writer.flush().unwrap();
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&objects).unwrap());
+    }
+
+    if !args.simple && !rows.is_empty() {
+        create_table(rows);
+    }
+}
+
+fn create_table(rows: Vec<Row>) {
+    let mut table = Table::new(vec![
+        Border::Double.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Double.into(),
+    ]);
+
+    table.append_row(Border::Double.into());
+    table.append_row(Row::flexible_height(vec![
+        Cell::left("char"),
+        Cell::left("hex"),
+        Cell::left("dec"),
+        Cell::left("oct"),
+        Cell::left("bin"),
+    ]));
+    table.append_row(Border::Single.into());
+
+    for r in rows {
+        table.append_row(r);
+    }
+
+    table.append_row(Border::Double.into());
+    print!("{}", table);
+}
+
+fn json_object(query: &str, ch: Option<char>) -> Value {
+    match ch {
+        Some(ch) => json!({
+            "query": query,
+            "found": true,
+            "name": display_repr(ch).unwrap_or_default(),
+            "number": ch as u8,
+            "hex": format!("0x{:02X}", ch as u8),
+            "oct": format!("0o{:03o}", ch as u8),
+            "bin": format!("0b{:07b}", ch as u8),
+        }),
+        None => json!({
+            "query": query,
+            "found": false,
+            "name": null,
+            "number": null,
+            "hex": null,
+            "oct": null,
+            "bin": null,
+        }),
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{ascii, errno, signal};
+use clap::{Parser, ValueEnum};
+use ratatui::{
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+        terminal,
+    },
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Row, Table, TableState, Tabs, Wrap},
+    DefaultTerminal, Frame,
+};
+use std::io;
+
+/// Browse the tables in a full-screen terminal interface
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(value_enum, default_value_t = Kind::Errno)]
+    /// Table to show first
+    table: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Kind {
+    Errno,
+    Signal,
+    Ascii,
+}
+
+const KINDS: [Kind; 3] = [Kind::Errno, Kind::Signal, Kind::Ascii];
+
+impl Kind {
+    fn title(self) -> &'static str {
+        match self {
+            Kind::Errno => "errno",
+            Kind::Signal => "signal",
+            Kind::Ascii => "ascii",
+        }
+    }
+
+    fn header(self) -> [&'static str; 3] {
+        match self {
+            Kind::Errno | Kind::Signal => ["name", "number", "description"],
+            Kind::Ascii => ["char", "dec", "hex"],
+        }
+    }
+
+    fn entries(self) -> Vec<Entry> {
+        match self {
+            Kind::Errno => errno::list()
+                .iter()
+                .filter_map(|name| errno::ErrnoDesc::from_name(name))
+                .map(|errdesc| {
+                    Entry::described(
+                        errdesc.name(),
+                        errdesc.number(),
+                        errdesc.manpages_description(),
+                        errdesc.libc_description(),
+                        errdesc.aliases(),
+                    )
+                })
+                .collect(),
+            Kind::Signal => signal::list()
+                .iter()
+                .filter_map(|name| signal::SignalDesc::from_name(name))
+                .map(|sigdesc| {
+                    Entry::described(
+                        sigdesc.name(),
+                        sigdesc.number(),
+                        sigdesc.manpages_description(),
+                        sigdesc.libc_description(),
+                        sigdesc.aliases(),
+                    )
+                })
+                .collect(),
+            Kind::Ascii => ascii::list().map(Entry::ascii).collect(),
+        }
+    }
+}
+
+struct Entry {
+    cells: [String; 3],
+    details: Vec<(&'static str, String)>,
+}
+
+impl Entry {
+    fn described(
+        name: &str,
+        number: Option<libc::c_int>,
+        manpages: String,
+        libc: String,
+        aliases: Vec<String>,
+    ) -> Self {
+        let number = number
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "-".to_owned());
+        let mut details = vec![
+            ("name", name.to_owned()),
+            ("number", number.clone()),
+            ("man-pages", manpages.clone()),
+            ("libc", libc),
+        ];
+        if !aliases.is_empty() {
+            details.push(("aliases", aliases.join(", ")));
+        }
+        Self {
+            cells: [name.to_owned(), number, manpages],
+            details,
+        }
+    }
+
+    fn ascii(ch: char) -> Self {
+        let code = ch as u8;
+        let mut details = vec![
+            (
+                "char",
+                ascii::display_repr(ch).unwrap_or_default().to_owned(),
+            ),
+            ("dec", code.to_string()),
+            ("hex", format!("0x{:02X}", code)),
+            ("oct", format!("0o{:03o}", code)),
+            ("bin", format!("0b{:07b}", code)),
+        ];
+        if ch.is_ascii_control() {
+            details.push(("caret", format!("^{}", (code ^ 0x40) as char)));
+        }
+        Self {
+            cells: [
+                ascii::display_repr(ch).unwrap_or_default().to_owned(),
+                code.to_string(),
+                format!("0x{:02X}", code),
+            ],
+            details,
+        }
+    }
+
+    fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_ascii_lowercase();
+        self.cells
+            .iter()
+            .any(|cell| cell.to_ascii_lowercase().contains(&filter))
+    }
+}
+
+/// Starts the browser and returns when the user quits
+pub fn run(args: Args) {
+    let mut terminal = match ratatui::try_init() {
+        Ok(terminal) => terminal,
+        Err(err) => {
+            // the alternate screen is entered last, only raw mode may be left on
+            let _ = terminal::disable_raw_mode();
+            eprintln!("browse: cannot set up the terminal: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let result = App::new(args.table).run(&mut terminal);
+    ratatui::restore();
+
+    if let Err(err) = result {
+        eprintln!("browse: {}", err);
+        std::process::exit(1);
+    }
+}
+
+struct App {
+    kind: Kind,
+    entries: Vec<Entry>,
+    filter: String,
+    table: TableState,
+    detail: bool,
+    quit: bool,
+}
+
+impl App {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            entries: kind.entries(),
+            filter: String::new(),
+            table: TableState::default().with_selected(0),
+            detail: false,
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn visible(&self) -> Vec<&Entry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matches(&self.filter))
+            .collect()
+    }
+
+    fn switch_to(&mut self, kind: Kind) {
+        self.kind = kind;
+        self.entries = kind.entries();
+        self.filter.clear();
+        self.table.select(Some(0));
+        self.detail = false;
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let len = self.visible().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, len as isize - 1);
+        self.table.select(Some(next as usize));
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        let position = KINDS.iter().position(|kind| *kind == self.kind).unwrap();
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.quit = true;
+            }
+            KeyCode::Tab => self.switch_to(KINDS[(position + 1) % KINDS.len()]),
+            KeyCode::BackTab => self.switch_to(KINDS[(position + KINDS.len() - 1) % KINDS.len()]),
+            KeyCode::Up => self.move_by(-1),
+            KeyCode::Down => self.move_by(1),
+            KeyCode::PageUp => self.move_by(-10),
+            KeyCode::PageDown => self.move_by(10),
+            KeyCode::Home => self.move_by(isize::MIN / 2),
+            KeyCode::End => self.move_by(isize::MAX / 2),
+            KeyCode::Enter => self.detail = !self.detail,
+            KeyCode::Esc => {
+                // close the detail pane first, then clear the filter, then quit
+                if self.detail {
+                    self.detail = false;
+                } else if !self.filter.is_empty() {
+                    self.filter.clear();
+                    self.table.select(Some(0));
+                } else {
+                    self.quit = true;
+                }
+            }
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.table.select(Some(0));
+            }
+            KeyCode::Char(ch) => {
+                self.filter.push(ch);
+                self.table.select(Some(0));
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [tabs_area, filter_area, main_area, help_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let position = KINDS.iter().position(|kind| *kind == self.kind).unwrap();
+        let tabs = Tabs::new(KINDS.iter().map(|kind| kind.title()))
+            .select(position)
+            .highlight_style(Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+        frame.render_widget(tabs, tabs_area);
+
+        let filter = Line::from(vec![
+            Span::styled("filter: ", Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(self.filter.as_str()),
+        ]);
+        frame.render_widget(Paragraph::new(filter), filter_area);
+
+        let (table_area, detail_area) = if self.detail {
+            let [table_area, detail_area] =
+                Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(main_area);
+            (table_area, Some(detail_area))
+        } else {
+            (main_area, None)
+        };
+        self.draw_table(frame, table_area);
+        if let Some(detail_area) = detail_area {
+            self.draw_detail(frame, detail_area);
+        }
+
+        let help = "type to filter  ↑/↓ move  Enter details  Tab switch table  Esc back/quit";
+        frame.render_widget(
+            Paragraph::new(help).style(Style::new().add_modifier(Modifier::DIM)),
+            help_area,
+        );
+    }
+
+    fn draw_table(&mut self, frame: &mut Frame, area: Rect) {
+        let header = Row::new(self.kind.header())
+            .style(Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED));
+        let rows: Vec<Row> = self
+            .visible()
+            .iter()
+            .map(|entry| Row::new(entry.cells.clone()))
+            .collect();
+        let widths = [
+            Constraint::Length(16),
+            Constraint::Length(6),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(self.kind.title()))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, area, &mut self.table);
+    }
+
+    fn draw_detail(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible();
+        let entry = self.table.selected().and_then(|i| visible.get(i));
+        let lines: Vec<Line> = match entry {
+            Some(entry) => entry
+                .details
+                .iter()
+                .map(|(label, value)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<10}", label),
+                            Style::new().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(value.as_str()),
+                    ])
+                })
+                .collect(),
+            None => vec![Line::from("no matching entries")],
+        };
+        let detail = Paragraph::new(lines)
+            .block(Block::bordered().title("details"))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(detail, area);
+    }
+}
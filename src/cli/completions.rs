@@ -0,0 +1,98 @@
+use crate::{ascii, errno, signal};
+use clap::{builder::PossibleValuesParser, Command, CommandFactory, Parser, ValueEnum};
+use clap_complete::Shell;
+use std::io;
+
+/// Generate shell completion scripts
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(value_enum)]
+    /// Shell to generate the completion script for
+    shell: Shell,
+
+    #[clap(long, value_enum, default_value_t = Bin::Lookup)]
+    /// Command to generate the completion script for
+    bin: Bin,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Bin {
+    Lookup,
+    Ascii,
+    Signal,
+    Errno,
+}
+
+/// Prints the completion script, completing queries with the known names
+///
+/// `lookup` is the command definition of the multicall binary.
+pub fn run(args: Args, lookup: Command) {
+    let (name, mut cmd) = match args.bin {
+        Bin::Lookup => (
+            "lookup",
+            lookup
+                .mut_subcommand("ascii", with_ascii_queries)
+                .mut_subcommand("signal", with_signal_queries)
+                .mut_subcommand("errno", with_errno_queries),
+        ),
+        Bin::Ascii => ("ascii", with_ascii_queries(super::ascii::Args::command())),
+        Bin::Signal => (
+            "signal",
+            with_signal_queries(super::signal::Args::command()),
+        ),
+        Bin::Errno => ("errno", with_errno_queries(super::errno::Args::command())),
+    };
+    clap_complete::generate(args.shell, &mut cmd, name, &mut io::stdout());
+
+    // clap_complete does not complete the values of positional arguments for fish
+    if let Shell::Fish = args.shell {
+        print_fish_queries(name, &cmd, None);
+    }
+}
+
+fn print_fish_queries(name: &str, cmd: &Command, condition: Option<String>) {
+    if let Some(query) = cmd.get_arguments().find(|arg| arg.get_id() == "query") {
+        let values: Vec<String> = query
+            .get_possible_values()
+            .iter()
+            .map(|value| value.get_name().replace('\\', "\\\\").replace('\'', "\\'"))
+            .collect();
+        if !values.is_empty() {
+            let condition = condition
+                .map(|cond| format!(" -n '{}'", cond))
+                .unwrap_or_default();
+            println!(
+                "complete -c {}{} -f -a '{}'",
+                name,
+                condition,
+                values.join(" ")
+            );
+        }
+    }
+
+    for sub in cmd.get_subcommands() {
+        let condition = format!("__fish_seen_subcommand_from {}", sub.get_name());
+        print_fish_queries(name, sub, Some(condition));
+    }
+}
+
+fn with_ascii_queries(cmd: Command) -> Command {
+    let carets = ascii::list()
+        .filter(|ch| ch.is_ascii_control())
+        .map(|ch| format!("^{}", ((ch as u8) ^ 0x40) as char));
+    with_queries(cmd, carets)
+}
+
+fn with_signal_queries(cmd: Command) -> Command {
+    with_queries(cmd, signal::list())
+}
+
+fn with_errno_queries(cmd: Command) -> Command {
+    with_queries(cmd, errno::list())
+}
+
+// Only used for generating the scripts; restricting the values would break parsing of numbers.
+fn with_queries(cmd: Command, queries: impl IntoIterator<Item = String>) -> Command {
+    let parser = PossibleValuesParser::new(queries);
+    cmd.mut_arg("query", |arg| arg.value_parser(parser))
+}
@@ -0,0 +1,301 @@
+use super::highlight::highlight;
+use super::{csv_writer, Format};
+use crate::arch::Arch;
+use crate::errno::{list_in, search_in, suggest_in, ErrnoDesc};
+use crate::os::Os;
+use crate::search::split_words;
+use box_drawing_table::{ansi_term::Style, Align, Border, Cell, CellSize, Column, Row, Table};
+use clap::Parser;
+use libc::c_int;
+use serde_json::{json, Value};
+use std::io;
+
+/// A simple utility to look up Linux error numbers (errno)
+#[derive(Debug, Parser)]
+#[clap(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(value_parser)]
+    /// errno value (e.g. "2"),
+    /// or symbolic name (e.g. "ENOENT")
+    query: Vec<String>,
+
+    #[clap(long, default_value_t = false)]
+    /// Disable pretty-printing
+    simple: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["simple", "jsonl"])]
+    /// Print the results as a JSON array
+    json: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with = "simple")]
+    /// Print the results as newline-delimited JSON (one object per line)
+    jsonl: bool,
+
+    #[clap(long, value_enum, conflicts_with_all = ["simple", "json", "jsonl"])]
+    /// Export the results as CSV or TSV with a header row
+    format: Option<Format>,
+
+    #[clap(short, long, default_value_t = false)]
+    /// List all errors
+    list: bool,
+
+    #[clap(long, num_args = 1.., conflicts_with_all = ["query", "list"])]
+    /// Search the descriptions for words (e.g. "too many open files")
+    search: Vec<String>,
+
+    #[clap(long, default_value_t = false)]
+    /// Display the description using strerror(3)
+    libc: bool,
+
+    #[clap(long, value_enum, default_value_t = Arch::Generic)]
+    /// Use the errno values of another architecture (generic: x86, ARM, RISC-V and most others)
+    arch: Arch,
+
+    #[clap(long, default_value_t = false)]
+    /// Show the errno values on every architecture side by side
+    all_arches: bool,
+
+    #[clap(long, value_enum, default_value_t = Os::Linux, conflicts_with_all = ["arch", "all_arches"])]
+    /// Use the errno values of another system (FreeBSD, macOS, OpenBSD, illumos or WASI)
+    os: Os,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["query", "list", "search", "simple", "json", "jsonl", "format", "all_arches"])]
+    /// Copy the standard input line by line, explaining the errors it mentions (e.g. "os error 24")
+    annotate: bool,
+
+    #[clap(long, default_value_t = false, requires = "query", conflicts_with_all = ["list", "search", "annotate", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Run the command given after "--" under ptrace(2) and summarize the system calls that failed
+    trace: bool,
+}
+
+/// Looks up the queried errors and prints them in the requested format
+pub fn run(args: Args) {
+    if args.trace {
+        super::trace::run(&args.query, args.simple);
+    }
+    if args.annotate {
+        super::annotate::run("errno", io::stdin().lock(), io::stdout().lock(), |line| {
+            crate::annotate::errors(args.os, args.arch, line)
+                .into_iter()
+                .map(|mention| {
+                    let description = if args.libc {
+                        mention.desc.libc_description()
+                    } else {
+                        mention.desc.manpages_description()
+                    };
+                    let note = if mention.by_name {
+                        description
+                    } else {
+                        format!("{}: {}", mention.desc.name(), description)
+                    };
+                    (mention.end, note)
+                })
+                .collect()
+        });
+        return;
+    }
+
+    let arches = if args.all_arches {
+        Arch::ALL.to_vec()
+    } else {
+        vec![args.arch]
+    };
+    let mut header = vec!["name"];
+    if args.all_arches {
+        header.extend(arches.iter().map(|arch| arch.name()));
+    } else {
+        header.push("number");
+    }
+    header.push("description");
+
+    let mut rows = Vec::new();
+    let mut objects = Vec::new();
+    let mut writer = args.format.map(|format| {
+        let mut writer = csv_writer(format);
+        writer.write_record(&header).unwrap();
+        writer
+    });
+    let mut desc_len = 0_usize;
+
+    let queries = if args.list {
+        list_in(args.os)
+    } else if !args.search.is_empty() {
+        search_in(args.os, &args.search)
+            .iter()
+            .map(|desc| desc.name().to_owned())
+            .collect()
+    } else {
+        args.query
+    };
+    for q in queries {
+        let sigdesc = if let Ok(num) = q.parse::<c_int>() {
+            match args.os {
+                Os::Linux => ErrnoDesc::from_number_on(args.arch, num),
+                os => ErrnoDesc::from_number_in(os, num),
+            }
+            .ok_or(Some(num))
+        } else {
+            ErrnoDesc::from_name_in(args.os, &q)
+                .map(|errdesc| errdesc.on(args.arch))
+                .ok_or_else(|| {
+                    super::warn_unknown("error", &q, &suggest_in(args.os, &q));
+                    None
+                })
+        };
+
+        if args.json || args.jsonl {
+            let object = json_object(&q, &sigdesc, args.libc, args.all_arches, args.os);
+            if args.jsonl {
+                println!("{}", object);
+            } else {
+                objects.push(object);
+            }
+            continue;
+        }
+
+        let name: String;
+        let numbers: Vec<String>;
+        let description: String;
+        match sigdesc {
+            Ok(sigdesc) => {
+                name = sigdesc.name().to_string();
+                numbers = arches
+                    .iter()
+                    .map(|arch| {
+                        sigdesc
+                            .on(*arch)
+                            .number()
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|| "-".to_owned())
+                    })
+                    .collect();
+
+                if args.libc {
+                    description = sigdesc.libc_description();
+                } else {
+                    description = sigdesc.manpages_description();
+                }
+            }
+            Err(num) => {
+                name = "-".to_owned();
+                numbers = arches
+                    .iter()
+                    .map(|arch| match num {
+                        Some(n) if *arch == args.arch => n.to_string(),
+                        _ => "-".to_owned(),
+                    })
+                    .collect();
+                description = "Unknown error".to_owned();
+            }
+        }
+
+        if let Some(writer) = &mut writer {
+            let record = [&name].into_iter().chain(&numbers).chain([&description]);
+            writer.write_record(record).unwrap();
+        } else if args.simple {
+            println!("{} {} {}", name, numbers.join(" "), description);
+        } else {
+            desc_len = desc_len.max(description.len());
+
+            let mut cells = vec![Cell {
+                value: name,
+                align: Align::Left,
+                style: Style::default().bold(),
+            }];
+            cells.extend(numbers.into_iter().map(Cell::left));
+            cells.push(Cell::left(description));
+            rows.push(Row::flexible_height(cells));
+        }
+    }
+
+    if let Some(mut writer) = writer {
+        writer.flush().unwrap();
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&objects).unwrap());
+    }
+
+    if !args.simple && !rows.is_empty() {
+        create_table(&header, rows, desc_len.min(80), &split_words(&args.search));
+    }
+}
+
+fn create_table(header: &[&str], rows: Vec<Row>, desc_len: usize, highlights: &[String]) {
+    let mut columns = vec![Border::Double.into(), Column::flexible_width()];
+    for _ in 2..header.len() {
+        columns.push(Border::Single.into());
+        columns.push(Column::flexible_width());
+    }
+    columns.push(Border::Single.into());
+    columns.push(Column::Cells {
+        width: CellSize::Fixed(desc_len),
+    });
+    columns.push(Border::Double.into());
+    let mut table = Table::new(columns);
+
+    table.append_row(Border::Double.into());
+    table.append_row(Row::flexible_height(
+        header.iter().map(|title| Cell::left(*title)).collect(),
+    ));
+    table.append_row(Border::Single.into());
+
+    for r in rows {
+        table.append_row(r);
+    }
+
+    table.append_row(Border::Double.into());
+
+    print!("{}", highlight(&table.to_string(), highlights));
+}
+
+fn json_object(
+    query: &str,
+    errdesc: &Result<ErrnoDesc, Option<c_int>>,
+    libc: bool,
+    all_arches: bool,
+    os: Os,
+) -> Value {
+    let mut object = match errdesc {
+        Ok(errdesc) => {
+            let (description, source) = if libc {
+                (errdesc.libc_description(), "libc")
+            } else {
+                (errdesc.manpages_description(), "man-pages")
+            };
+            json!({
+                "query": query,
+                "found": true,
+                "name": errdesc.name(),
+                "number": errdesc.number(),
+                "description": description,
+                "description_source": source,
+                "aliases": errdesc.aliases(),
+            })
+        }
+        Err(num) => json!({
+            "query": query,
+            "found": false,
+            "name": null,
+            "number": num,
+            "description": null,
+            "description_source": null,
+            "aliases": [],
+            "suggestions": if num.is_none() { suggest_in(os, query) } else { Vec::new() },
+        }),
+    };
+    if all_arches {
+        let numbers: serde_json::Map<String, Value> = Arch::ALL
+            .iter()
+            .map(|arch| {
+                let number = errdesc
+                    .as_ref()
+                    .ok()
+                    .and_then(|errdesc| errdesc.on(*arch).number());
+                (arch.name().to_owned(), json!(number))
+            })
+            .collect();
+        object["numbers"] = Value::Object(numbers);
+    }
+    object
+}
@@ -0,0 +1,49 @@
+use box_drawing_table::ansi_term::{Colour, Style};
+
+/// Highlights the words in the last column of a rendered table, ignoring case
+///
+/// The table has to be rendered first since the cells are measured including escape sequences.
+pub(crate) fn highlight(table: &str, words: &[String]) -> String {
+    let style = Colour::Yellow.bold();
+
+    let mut highlighted = String::new();
+    for (i, line) in table.lines().enumerate() {
+        // skip the top border, the header and the border below it
+        match line.rfind('│') {
+            Some(pos) if i >= 3 => {
+                let (cells, last) = line.split_at(pos + '│'.len_utf8());
+                highlighted.push_str(cells);
+                highlighted.push_str(&highlight_words(last, words, style));
+            }
+            _ => highlighted.push_str(line),
+        }
+        highlighted.push('\n');
+    }
+    highlighted
+}
+
+fn highlight_words(text: &str, words: &[String], style: Style) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut marked = vec![false; text.len()];
+    for word in words.iter().filter(|word| !word.is_empty()) {
+        for (start, _) in lower.match_indices(word.as_str()) {
+            marked[start..start + word.len()].fill(true);
+        }
+    }
+
+    let mut highlighted = String::new();
+    let mut start = 0;
+    while start < text.len() {
+        let end = (start..text.len())
+            .find(|&i| marked[i] != marked[start])
+            .unwrap_or(text.len());
+        let segment = &text[start..end];
+        if marked[start] {
+            highlighted.push_str(&style.paint(segment).to_string());
+        } else {
+            highlighted.push_str(segment);
+        }
+        start = end;
+    }
+    highlighted
+}
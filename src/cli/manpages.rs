@@ -0,0 +1,132 @@
+use crate::{errno, signal};
+use clap::{Command, CommandFactory, Parser};
+use clap_mangen::Man;
+use roff::{bold, roman, Roff};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// Generate man pages for all commands
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(value_parser)]
+    /// Directory to write the pages to
+    out_dir: PathBuf,
+}
+
+/// Writes `lookup.1`, `ascii-lookup.1`, `signal-lookup.1` and `errno-lookup.1` to the directory
+///
+/// `lookup` is the command definition of the multicall binary.
+pub fn run(args: Args, lookup: Command) {
+    let pages = [
+        ("lookup", lookup, Appendix::All),
+        ("ascii", super::ascii::Args::command(), Appendix::None),
+        ("signal", super::signal::Args::command(), Appendix::Signals),
+        ("errno", super::errno::Args::command(), Appendix::Errors),
+    ];
+
+    for (name, cmd, appendix) in pages {
+        if let Err(err) = write_page(&args.out_dir, name, cmd, appendix) {
+            eprintln!("manpages: cannot write the man page of {}: {}", name, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Appendix {
+    None,
+    Signals,
+    Errors,
+    All,
+}
+
+fn write_page(out_dir: &Path, name: &str, cmd: Command, appendix: Appendix) -> io::Result<()> {
+    // "lookup" itself is unambiguous; the others would be shadowed by e.g. errno(3)
+    let title = if name == "lookup" {
+        name.to_owned()
+    } else {
+        format!("{}-lookup", name)
+    };
+
+    let cmd = cmd.name(name.to_owned()).disable_help_subcommand(true);
+    let man = Man::new(cmd.clone()).title(title.to_uppercase());
+    let mut file = BufWriter::new(File::create(out_dir.join(format!("{}.1", title)))?);
+
+    man.render_title(&mut file)?;
+    man.render_name_section(&mut file)?;
+    man.render_synopsis_section(&mut file)?;
+    man.render_description_section(&mut file)?;
+    man.render_options_section(&mut file)?;
+
+    let mut roff = Roff::new();
+    if cmd.has_subcommands() {
+        subcommands_section(&mut roff, &cmd);
+    }
+    if let Appendix::Signals | Appendix::All = appendix {
+        signals_section(&mut roff);
+    }
+    if let Appendix::Errors | Appendix::All = appendix {
+        errors_section(&mut roff);
+    }
+    roff.to_writer(&mut file)?;
+
+    man.render_version_section(&mut file)?;
+    file.flush()
+}
+
+// Unlike clap_mangen, refer to the pages of the standalone commands
+fn subcommands_section(roff: &mut Roff, cmd: &Command) {
+    roff.control("SH", ["SUBCOMMANDS"]);
+    for sub in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+        let name = sub.get_name();
+        let about = sub.get_about().map(|about| about.to_string());
+        roff.control("TP", []);
+        roff.text([bold(name)]);
+        roff.text([roman(about.unwrap_or_default())]);
+        if let "ascii" | "signal" | "errno" = name {
+            roff.text([roman(format!("See {}-lookup(1).", name))]);
+        }
+    }
+}
+
+fn signals_section(roff: &mut Roff) {
+    roff.control("SH", ["SIGNALS"]);
+    for sigdesc in signal::list()
+        .iter()
+        .filter_map(|name| signal::SignalDesc::from_name(name))
+    {
+        entry(
+            roff,
+            sigdesc.name(),
+            sigdesc.number(),
+            &sigdesc.manpages_description(),
+        );
+    }
+}
+
+fn errors_section(roff: &mut Roff) {
+    roff.control("SH", ["ERROR NUMBERS"]);
+    for errdesc in errno::list()
+        .iter()
+        .filter_map(|name| errno::ErrnoDesc::from_name(name))
+    {
+        entry(
+            roff,
+            errdesc.name(),
+            errdesc.number(),
+            &errdesc.manpages_description(),
+        );
+    }
+}
+
+fn entry(roff: &mut Roff, name: &str, number: Option<libc::c_int>, description: &str) {
+    let number = number
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "-".to_owned());
+    roff.control("TP", []);
+    roff.text([bold(name), roman(format!(" ({})", number))]);
+    roff.text([roman(description)]);
+}
@@ -0,0 +1,71 @@
+//! Command-line front-ends shared by the `ascii`, `signal`, `errno` and `lookup` binaries.
+
+use clap::ValueEnum;
+use std::io;
+
+pub mod ascii;
+
+cfg_linux! {
+    mod annotate;
+    pub mod browse;
+    pub mod completions;
+    pub mod errno;
+    mod highlight;
+    pub mod manpages;
+    pub mod signal;
+    pub mod strace;
+    mod trace;
+    mod wait;
+}
+
+/// The formats of "--format"
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Tsv,
+}
+
+/// Returns a writer of the format to the standard output
+fn csv_writer(format: Format) -> csv::Writer<io::Stdout> {
+    let delimiter = match format {
+        Format::Csv => b',',
+        Format::Tsv => b'\t',
+    };
+    csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(io::stdout())
+}
+
+cfg_linux! {
+    /// Exits like shells do for a command that could not be started:
+    /// 127 if it is not found, 126 if it cannot be executed
+    fn exit_not_started(err: &io::Error) -> ! {
+        if err.kind() == io::ErrorKind::NotFound {
+            std::process::exit(127);
+        }
+        std::process::exit(126);
+    }
+
+    /// Exits like a command that ended with the wait status: with its exit status,
+    /// or 128 plus the signal that killed it
+    fn exit_like(status: libc::c_int) -> ! {
+        if libc::WIFSIGNALED(status) {
+            std::process::exit(128 + libc::WTERMSIG(status));
+        }
+        std::process::exit(libc::WEXITSTATUS(status));
+    }
+
+    /// Warns that a name is unknown, mentioning the closest known names if any
+    fn warn_unknown(kind: &str, name: &str, suggestions: &[String]) {
+        if suggestions.is_empty() {
+            eprintln!("warning: unknown {} name '{}'", kind, name);
+        } else {
+            eprintln!(
+                "warning: unknown {} name '{}'; did you mean {}?",
+                kind,
+                name,
+                suggestions.join(" or ")
+            );
+        }
+    }
+}
@@ -0,0 +1,739 @@
+use super::highlight::highlight;
+use super::{csv_writer, Format};
+use crate::arch::Arch;
+use crate::os::Os;
+use crate::search::split_words;
+use crate::sigmask::{self, Masks};
+use crate::signal::{any_codes, list, list_in, list_on, search_in, suggest_in, Code, SignalDesc};
+use box_drawing_table::{ansi_term::Style, Align, Border, Cell, Column, Row, Table};
+use clap::Parser;
+use libc::c_int;
+use serde_json::{json, Value};
+use std::io;
+
+/// A simple utility to look up Linux signals
+#[derive(Debug, Parser)]
+#[clap(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(value_parser, allow_negative_numbers = true)]
+    /// signal number (e.g. "2"),
+    /// signal name (e.g. "SIGINT"),
+    /// or shell status code (e.g. "130") if "-s" option is specified
+    query: Vec<String>,
+
+    #[clap(long, default_value_t = false)]
+    /// Disable pretty-printing
+    simple: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["simple", "jsonl"])]
+    /// Print the results as a JSON array
+    json: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with = "simple")]
+    /// Print the results as newline-delimited JSON (one object per line)
+    jsonl: bool,
+
+    #[clap(long, value_enum, conflicts_with_all = ["simple", "json", "jsonl"])]
+    /// Export the results as CSV or TSV with a header row
+    format: Option<Format>,
+
+    #[clap(short, long, default_value_t = false)]
+    /// List all signals
+    list: bool,
+
+    #[clap(long, num_args = 1.., conflicts_with_all = ["query", "list"])]
+    /// Search the descriptions for words (e.g. "broken pipe")
+    search: Vec<String>,
+
+    #[clap(long, value_name = "KEY=VALUE", value_parser = parse_filter, conflicts_with_all = ["annotate", "run", "wait_status", "pid", "who", "to_mask"])]
+    /// Only show the signals with a default action (e.g. "action=core"), catchable or not
+    /// ("catchable=no"), or usually synchronous or not ("sync=yes"); lists all signals without a query
+    filter: Vec<Filter>,
+
+    #[clap(short, long, default_value_t = false)]
+    /// Interpret numbers as status code instead of signal number
+    status: bool,
+
+    #[clap(long, default_value_t = false)]
+    /// Display the description using strsignal(3)
+    libc: bool,
+
+    #[clap(long, value_enum, default_value_t = Arch::Generic)]
+    /// Use the signal numbers of another architecture (generic: x86, ARM, RISC-V and most others)
+    arch: Arch,
+
+    #[clap(long, default_value_t = false)]
+    /// Show the signal numbers on every architecture side by side
+    all_arches: bool,
+
+    #[clap(long, value_enum, default_value_t = Os::Linux, conflicts_with_all = ["arch", "all_arches"])]
+    /// Use the signal numbers of another system (FreeBSD, macOS, OpenBSD, illumos or WASI)
+    os: Os,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["query", "list", "search", "simple", "json", "jsonl", "format", "all_arches"])]
+    /// Copy the standard input line by line, explaining the signals it mentions (e.g. "exit status 137")
+    annotate: bool,
+
+    #[clap(long, default_value_t = false, requires = "query", conflicts_with_all = ["list", "search", "status", "annotate", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Run the command given after "--" and explain its exit status or the signal that killed it
+    run: bool,
+
+    #[clap(long, default_value_t = false, requires = "query", conflicts_with_all = ["list", "search", "status", "annotate", "run", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Interpret numbers as wait statuses of waitpid(2) (e.g. "134" or "0x8b") and decode them
+    wait_status: bool,
+
+    #[clap(long, conflicts_with_all = ["query", "list", "search", "annotate", "run", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Show the signals pending, blocked, ignored or caught by the process, from /proc/<PID>/status
+    pid: Option<u32>,
+
+    #[clap(long, conflicts_with_all = ["query", "list", "search", "status", "annotate", "run", "wait_status", "pid", "os"])]
+    /// Decode a signal mask written in hexadecimal (e.g. "0000000000004a03" from /proc or `ps -o blocked`)
+    mask: Option<String>,
+
+    #[clap(long, default_value_t = false, requires = "query", conflicts_with_all = ["list", "search", "status", "annotate", "run", "wait_status", "pid", "mask", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Print the mask of the signals given as queries, in hexadecimal
+    to_mask: bool,
+
+    #[clap(long, default_value_t = false, conflicts_with_all = ["list", "search", "status", "annotate", "run", "wait_status", "pid", "mask", "to_mask", "filter", "json", "jsonl", "format", "all_arches", "os"])]
+    /// Explain the si_code values given after the signal (e.g. "SIGSEGV 1"), list those of the signal,
+    /// or list those of every signal without a query
+    code: bool,
+
+    #[clap(long, value_name = "SIGNAL", conflicts_with_all = ["query", "list", "search", "annotate", "run", "pid", "mask", "to_mask", "code", "json", "jsonl", "format", "all_arches", "os"])]
+    /// List the processes that block, ignore or catch the signal (e.g. "SIGTERM")
+    who: Option<String>,
+
+    #[clap(long, default_value_t = false, requires = "who")]
+    /// With "--who", only list the processes that block the signal
+    blocked: bool,
+
+    #[clap(long, default_value_t = false, requires = "who")]
+    /// With "--who", only list the processes that ignore the signal
+    ignored: bool,
+
+    #[clap(long, default_value_t = false, requires = "who")]
+    /// With "--who", only list the processes that catch the signal
+    caught: bool,
+}
+
+/// A condition of "--filter"
+#[derive(Debug, Clone)]
+enum Filter {
+    /// The default action, in lowercase
+    Action(String),
+    Catchable(bool),
+    Sync(bool),
+}
+
+/// The default actions of signal(7)
+const ACTIONS: [&str; 5] = ["term", "ign", "core", "stop", "cont"];
+
+fn parse_filter(filter: &str) -> Result<Filter, String> {
+    let (key, value) = filter
+        .split_once('=')
+        .ok_or("expected KEY=VALUE (e.g. \"action=core\")")?;
+    let value = value.to_ascii_lowercase();
+    let flag = || match value.as_str() {
+        "yes" => Ok(true),
+        "no" => Ok(false),
+        _ => Err(format!("expected yes or no for {}, not '{}'", key, value)),
+    };
+    match key {
+        "action" if ACTIONS.contains(&value.as_str()) => Ok(Filter::Action(value)),
+        "action" => Err(format!(
+            "expected term, ign, core, stop or cont for action, not '{}'",
+            value
+        )),
+        "catchable" => flag().map(Filter::Catchable),
+        "sync" => flag().map(Filter::Sync),
+        _ => Err(format!(
+            "unknown key '{}'; expected action, catchable or sync",
+            key
+        )),
+    }
+}
+
+impl Filter {
+    fn matches(&self, sigdesc: &SignalDesc) -> bool {
+        match self {
+            Filter::Action(action) => sigdesc
+                .action()
+                .is_some_and(|own| own.eq_ignore_ascii_case(action)),
+            Filter::Catchable(catchable) => sigdesc.catchable() == *catchable,
+            Filter::Sync(sync) => sigdesc.synchronous() == *sync,
+        }
+    }
+}
+
+/// Looks up the queried signals and prints them in the requested format
+pub fn run(args: Args) {
+    if args.run {
+        super::wait::run(&args.query, args.libc);
+    }
+    if let Some(pid) = args.pid {
+        process(pid, args.libc, args.simple);
+        return;
+    }
+    if let Some(signal) = &args.who {
+        who(signal, &args);
+        return;
+    }
+    if args.code {
+        codes(&args.query, args.arch, args.simple);
+        return;
+    }
+    if args.to_mask {
+        to_mask(&args.query, args.arch);
+        return;
+    }
+    if args.wait_status {
+        super::wait::decode(&args.query, args.libc);
+        return;
+    }
+    if args.annotate {
+        super::annotate::run("signal", io::stdin().lock(), io::stdout().lock(), |line| {
+            crate::annotate::signals(args.os, args.arch, line)
+                .into_iter()
+                .map(|mention| {
+                    let description = if args.libc {
+                        mention.desc.libc_description()
+                    } else {
+                        mention.desc.manpages_description()
+                    };
+                    let note = if mention.by_name {
+                        description
+                    } else {
+                        format!("{}: {}", mention.desc.name(), description)
+                    };
+                    (mention.end, note)
+                })
+                .collect()
+        });
+        return;
+    }
+
+    let arches = if args.all_arches {
+        Arch::ALL.to_vec()
+    } else {
+        vec![args.arch]
+    };
+    let mut header = vec!["name"];
+    if args.all_arches {
+        header.extend(arches.iter().map(|arch| arch.name()));
+    } else {
+        header.push("number");
+    }
+    header.extend(["action", "catchable", "sync", "description"]);
+
+    let mut rows = Vec::new();
+    let mut objects = Vec::new();
+    let mut writer = args.format.map(|format| {
+        let mut writer = csv_writer(format);
+        writer.write_record(&header).unwrap();
+        writer
+    });
+
+    let everything = args.query.is_empty() && args.search.is_empty() && args.mask.is_none();
+    let queries = if args.list || (!args.filter.is_empty() && everything) {
+        match args.os {
+            Os::Linux => list_on(args.arch),
+            os => list_in(os),
+        }
+    } else if !args.search.is_empty() {
+        search_in(args.os, &args.search)
+            .iter()
+            .map(|desc| desc.name().to_owned())
+            .collect()
+    } else if let Some(mask) = &args.mask {
+        match sigmask::parse(mask) {
+            Some(mask) => sigmask::numbers(mask)
+                .into_iter()
+                .map(|signum| signum.to_string())
+                .collect(),
+            None => {
+                eprintln!("signal: invalid signal mask '{}'", mask);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        args.query
+    };
+    for q in queries {
+        let sigdesc = if let Ok(mut num) = q.parse::<c_int>() {
+            if args.status {
+                num -= 128;
+            }
+            match args.os {
+                Os::Linux => SignalDesc::from_number_on(args.arch, num),
+                os => SignalDesc::from_number_in(os, num),
+            }
+            .ok_or(Some(num))
+        } else {
+            match args.os {
+                Os::Linux => SignalDesc::from_name_on(args.arch, &q),
+                os => SignalDesc::from_name_in(os, &q),
+            }
+            .ok_or_else(|| {
+                    super::warn_unknown("signal", &q, &suggest_in(args.os, &q));
+                    None
+                })
+        };
+        if !args.filter.is_empty()
+            && !sigdesc
+                .as_ref()
+                .is_ok_and(|sigdesc| args.filter.iter().all(|f| f.matches(sigdesc)))
+        {
+            continue;
+        }
+
+        if args.json || args.jsonl {
+            let object = json_object(&q, &sigdesc, args.libc, args.all_arches, args.os);
+            if args.jsonl {
+                println!("{}", object);
+            } else {
+                objects.push(object);
+            }
+            continue;
+        }
+
+        let name: String;
+        let numbers: Vec<String>;
+        let properties: [String; 3];
+        let description: String;
+        match sigdesc {
+            Ok(sigdesc) => {
+                name = sigdesc.name().to_string();
+                numbers = arches
+                    .iter()
+                    .map(|arch| {
+                        sigdesc
+                            .on(*arch)
+                            .number()
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|| "-".to_owned())
+                    })
+                    .collect();
+                let yes_no = |yes| if yes { "yes" } else { "no" }.to_owned();
+                properties = [
+                    sigdesc.action().unwrap_or("-").to_owned(),
+                    yes_no(sigdesc.catchable()),
+                    yes_no(sigdesc.synchronous()),
+                ];
+
+                if args.libc {
+                    description = sigdesc.libc_description();
+                } else {
+                    description = sigdesc.manpages_description();
+                }
+            }
+            Err(num) => {
+                name = "-".to_owned();
+                numbers = arches
+                    .iter()
+                    .map(|arch| match num {
+                        Some(n) if *arch == args.arch => n.to_string(),
+                        _ => "-".to_owned(),
+                    })
+                    .collect();
+                properties = ["-".to_owned(), "-".to_owned(), "-".to_owned()];
+                description = "Unknown signal".to_owned();
+            }
+        }
+
+        if let Some(writer) = &mut writer {
+            let record = [&name]
+                .into_iter()
+                .chain(&numbers)
+                .chain(&properties)
+                .chain([&description]);
+            writer.write_record(record).unwrap();
+        } else if args.simple {
+            println!(
+                "{} {} {} {}",
+                name,
+                numbers.join(" "),
+                properties.join(" "),
+                description
+            );
+        } else {
+            let mut cells = vec![Cell {
+                value: name,
+                align: Align::Left,
+                style: Style::default().bold(),
+            }];
+            cells.extend(numbers.into_iter().map(Cell::left));
+            cells.extend(properties.into_iter().map(Cell::left));
+            cells.push(Cell::left(description));
+            rows.push(Row::flexible_height(cells));
+        }
+    }
+
+    if let Some(mut writer) = writer {
+        writer.flush().unwrap();
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&objects).unwrap());
+    }
+
+    if !args.simple && !rows.is_empty() {
+        create_table(&header, rows, &split_words(&args.search));
+    }
+}
+
+/// Prints a row for each signal pending, blocked, ignored or caught by the process
+fn process(pid: u32, libc: bool, simple: bool) {
+    let masks = match Masks::read(pid) {
+        Ok(masks) => masks,
+        Err(err) => {
+            eprintln!("signal: cannot read the signal masks of {}: {}", pid, err);
+            std::process::exit(1);
+        }
+    };
+    let header = [
+        "name",
+        "number",
+        "pending",
+        "blocked",
+        "ignored",
+        "caught",
+        "description",
+    ];
+
+    let any = masks.pending | masks.shared_pending | masks.blocked | masks.ignored | masks.caught;
+    let mut rows = Vec::new();
+    for signum in sigmask::numbers(any) {
+        let mark = |mask| {
+            if sigmask::contains(mask, signum) {
+                "yes"
+            } else {
+                "-"
+            }
+        };
+        let pending = match (
+            sigmask::contains(masks.pending, signum),
+            sigmask::contains(masks.shared_pending, signum),
+        ) {
+            (true, true) => "both",
+            (true, false) => "thread",
+            (false, true) => "process",
+            (false, false) => "-",
+        };
+        let sigdesc = SignalDesc::from_number(signum);
+        let name = sigdesc
+            .as_ref()
+            .map_or_else(|| "-".to_owned(), |desc| desc.name().to_owned());
+        let description = match &sigdesc {
+            Some(desc) if libc => desc.libc_description(),
+            Some(desc) => desc.manpages_description(),
+            None => "Unknown signal".to_owned(),
+        };
+        let cells = [
+            signum.to_string(),
+            pending.to_owned(),
+            mark(masks.blocked).to_owned(),
+            mark(masks.ignored).to_owned(),
+            mark(masks.caught).to_owned(),
+            description,
+        ];
+
+        if simple {
+            println!("{} {}", name, cells.join(" "));
+        } else {
+            let mut row = vec![Cell {
+                value: name,
+                align: Align::Left,
+                style: Style::default().bold(),
+            }];
+            row.extend(cells.into_iter().map(Cell::left));
+            rows.push(Row::flexible_height(row));
+        }
+    }
+
+    if any == 0 {
+        println!(
+            "{} ({}) has no signal pending, blocked, ignored or caught",
+            masks.name, pid
+        );
+    } else if !simple {
+        create_table(&header, rows, &[]);
+    }
+}
+
+/// Prints a row for each queried si_code value of the signal, or for all of them;
+/// without a signal, prints the codes of every signal, then those of any signal
+fn codes(query: &[String], arch: Arch, simple: bool) {
+    let mut rows: Vec<[String; 4]> = Vec::new();
+    match query.split_first() {
+        Some((signal, values)) => {
+            let sigdesc = match signal.parse::<c_int>() {
+                Ok(num) => SignalDesc::from_number_on(arch, num),
+                Err(_) => SignalDesc::from_name_on(arch, signal),
+            };
+            let sigdesc = match sigdesc {
+                Some(sigdesc) => sigdesc,
+                None => {
+                    super::warn_unknown("signal", signal, &suggest_in(Os::Linux, signal));
+                    std::process::exit(1);
+                }
+            };
+            let name = sigdesc.name().to_owned();
+            if values.is_empty() {
+                for code in sigdesc.codes() {
+                    rows.push(code_row(&name, code));
+                }
+            }
+            for value in values {
+                let code = match value.strip_prefix("0x") {
+                    Some(hex) => c_int::from_str_radix(hex, 16).ok(),
+                    None => value.parse().ok(),
+                }
+                .and_then(|value| sigdesc.code(value))
+                .or_else(|| sigdesc.codes().into_iter().find(|code| code.name == value));
+                match code {
+                    Some(code) => rows.push(code_row(&name, code)),
+                    None => rows.push([
+                        name.clone(),
+                        "-".to_owned(),
+                        value.clone(),
+                        "Unknown code".to_owned(),
+                    ]),
+                }
+            }
+        }
+        None => {
+            let any = any_codes(arch);
+            for signal in list() {
+                let sigdesc = SignalDesc::from_name(&signal).unwrap().on(arch);
+                // synonyms would repeat the codes of their signal
+                if sigdesc.alias_of().is_some() {
+                    continue;
+                }
+                for code in sigdesc.codes() {
+                    if !any.iter().any(|shared| shared.name == code.name) {
+                        rows.push(code_row(&signal, code));
+                    }
+                }
+            }
+            rows.extend(any.iter().map(|code| code_row("any", code)));
+        }
+    }
+
+    if simple {
+        for row in rows {
+            println!("{}", row.join(" "));
+        }
+        return;
+    }
+    let rows = rows
+        .into_iter()
+        .map(|[signal, name, value, description]| {
+            Row::flexible_height(vec![
+                Cell::left(signal),
+                Cell {
+                    value: name,
+                    align: Align::Left,
+                    style: Style::default().bold(),
+                },
+                Cell::left(value),
+                Cell::left(description),
+            ])
+        })
+        .collect();
+    create_table(&["signal", "code", "value", "description"], rows, &[]);
+}
+
+fn code_row(signal: &str, code: &Code) -> [String; 4] {
+    let value = match code.value {
+        libc::SI_KERNEL => format!("{:#x}", code.value),
+        value => value.to_string(),
+    };
+    [
+        signal.to_owned(),
+        code.name.to_owned(),
+        value,
+        code.description.to_owned(),
+    ]
+}
+
+/// Prints the mask of the signals, given by name or number
+fn to_mask(queries: &[String], arch: Arch) {
+    let mut numbers = Vec::new();
+    for q in queries {
+        let num = match q.parse::<c_int>() {
+            Ok(num) => Some(num),
+            Err(_) => match SignalDesc::from_name_on(arch, q) {
+                Some(sigdesc) => sigdesc.number(),
+                None => {
+                    super::warn_unknown("signal", q, &suggest_in(Os::Linux, q));
+                    std::process::exit(1);
+                }
+            },
+        };
+        match num {
+            Some(num) => numbers.push(num),
+            None => {
+                eprintln!("signal: {} does not exist on {}", q, arch.name());
+                std::process::exit(1);
+            }
+        }
+    }
+    match sigmask::from_numbers(numbers) {
+        Some(mask) => println!("{}", sigmask::format(mask)),
+        None => {
+            eprintln!("signal: signal numbers range from 1 to 64");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints a row for each process that blocks, ignores or catches the signal,
+/// or only those selected by "--blocked", "--ignored" and "--caught"
+fn who(signal: &str, args: &Args) {
+    let signum = match signal.parse::<c_int>() {
+        Ok(num) => num,
+        Err(_) => match SignalDesc::from_name(signal).and_then(|desc| desc.number()) {
+            Some(num) => num,
+            None => {
+                super::warn_unknown("signal", signal, &suggest_in(Os::Linux, signal));
+                std::process::exit(1);
+            }
+        },
+    };
+    let name = SignalDesc::from_number(signum)
+        .map_or_else(|| signum.to_string(), |desc| desc.name().to_owned());
+    let processes = match sigmask::processes() {
+        Ok(processes) => processes,
+        Err(err) => {
+            eprintln!("signal: cannot list the processes: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let any = !(args.blocked || args.ignored || args.caught);
+    let header = ["pid", "comm", "blocked", "ignored", "caught", "cgroup"];
+
+    let mut rows = Vec::new();
+    for process in processes {
+        let masks = &process.masks;
+        let blocked = sigmask::contains(masks.blocked, signum);
+        let ignored = sigmask::contains(masks.ignored, signum);
+        let caught = sigmask::contains(masks.caught, signum);
+        let selected = (blocked && (any || args.blocked))
+            || (ignored && (any || args.ignored))
+            || (caught && (any || args.caught));
+        if !selected {
+            continue;
+        }
+        let mark = |set: bool| if set { "yes" } else { "-" }.to_owned();
+        let cells = [
+            masks.name.clone(),
+            mark(blocked),
+            mark(ignored),
+            mark(caught),
+            process.cgroup,
+        ];
+
+        if args.simple {
+            println!("{} {}", process.pid, cells.join(" "));
+        } else {
+            let mut row = vec![Cell {
+                value: process.pid.to_string(),
+                align: Align::Right,
+                style: Style::default().bold(),
+            }];
+            row.extend(cells.into_iter().map(Cell::left));
+            rows.push(Row::flexible_height(row));
+        }
+    }
+
+    if args.simple {
+        return;
+    }
+    if rows.is_empty() {
+        println!("no process blocks, ignores or catches {}", name);
+    } else {
+        create_table(&header, rows, &[]);
+    }
+}
+
+fn create_table(header: &[&str], rows: Vec<Row>, highlights: &[String]) {
+    let mut columns = vec![Border::Double.into(), Column::flexible_width()];
+    for _ in 1..header.len() {
+        columns.push(Border::Single.into());
+        columns.push(Column::flexible_width());
+    }
+    columns.push(Border::Double.into());
+    let mut table = Table::new(columns);
+
+    table.append_row(Border::Double.into());
+    table.append_row(Row::flexible_height(
+        header.iter().map(|title| Cell::left(*title)).collect(),
+    ));
+    table.append_row(Border::Single.into());
+
+    for r in rows {
+        table.append_row(r);
+    }
+
+    table.append_row(Border::Double.into());
+    print!("{}", highlight(&table.to_string(), highlights));
+}
+
+fn json_object(
+    query: &str,
+    sigdesc: &Result<SignalDesc, Option<c_int>>,
+    libc: bool,
+    all_arches: bool,
+    os: Os,
+) -> Value {
+    let mut object = match sigdesc {
+        Ok(sigdesc) => {
+            let (description, source) = if libc {
+                (sigdesc.libc_description(), "libc")
+            } else {
+                (sigdesc.manpages_description(), "man-pages")
+            };
+            json!({
+                "query": query,
+                "found": true,
+                "name": sigdesc.name(),
+                "number": sigdesc.number(),
+                "description": description,
+                "description_source": source,
+                "aliases": sigdesc.aliases(),
+                "kernel_name": sigdesc.kernel_name(),
+                "action": sigdesc.action(),
+                "catchable": sigdesc.catchable(),
+                "synchronous": sigdesc.synchronous(),
+            })
+        }
+        Err(num) => json!({
+            "query": query,
+            "found": false,
+            "name": null,
+            "number": num,
+            "description": null,
+            "description_source": null,
+            "aliases": [],
+            "kernel_name": null,
+            "action": null,
+            "catchable": null,
+            "synchronous": null,
+            "suggestions": if num.is_none() { suggest_in(os, query) } else { Vec::new() },
+        }),
+    };
+    if all_arches {
+        let numbers: serde_json::Map<String, Value> = Arch::ALL
+            .iter()
+            .map(|arch| {
+                let number = sigdesc
+                    .as_ref()
+                    .ok()
+                    .and_then(|sigdesc| sigdesc.on(*arch).number());
+                (arch.name().to_owned(), json!(number))
+            })
+            .collect();
+        object["numbers"] = Value::Object(numbers);
+    }
+    object
+}
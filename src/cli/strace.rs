@@ -0,0 +1,171 @@
+use crate::errno::ErrnoDesc;
+use crate::signal::SignalDesc;
+use crate::strace::{parse, Event};
+use box_drawing_table::{ansi_term::Style, Align, Border, Cell, CellSize, Column, Row, Table};
+use clap::Parser;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+/// Explain the errors and signals in the output of strace(1), then summarize them
+///
+/// Reads the log of `strace -o` or the output of `strace ... 2>&1`;
+/// the pid prefixes of `-f` and the timings of `-T` are understood.
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Log written by `strace -o` (default: the standard input)
+    file: Option<PathBuf>,
+
+    #[clap(short, long, default_value_t = false)]
+    /// Only print the summary, not the annotated lines
+    summary: bool,
+}
+
+/// How often each error or signal happened, by syscall (or pid) and name
+type Counts<K> = BTreeMap<(K, String), usize>;
+
+/// Copies the strace output with the man-pages descriptions, then prints the errors
+/// per syscall and the errors and signals per pid
+pub fn run(args: Args) {
+    let input: Box<dyn BufRead> = match &args.file {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("strace: cannot open {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+    let output: Box<dyn Write> = if args.summary {
+        Box::new(io::sink())
+    } else {
+        Box::new(io::stdout().lock())
+    };
+
+    let mut by_syscall: Counts<String> = BTreeMap::new();
+    let mut by_pid: Counts<Option<u32>> = BTreeMap::new();
+    super::annotate::run("strace", input, output, |line| {
+        let traced = match parse(line) {
+            Some(traced) => traced,
+            None => return Vec::new(),
+        };
+        let (name, description) = match traced.event {
+            Event::Error { syscall, name } => {
+                *by_syscall
+                    .entry((syscall.to_owned(), name.to_owned()))
+                    .or_default() += 1;
+                let description =
+                    ErrnoDesc::from_name(name).map(|desc| desc.manpages_description());
+                (name.to_owned(), description)
+            }
+            Event::Signal { name } => (name.to_owned(), signal_description(name)),
+            Event::Killed { name } => (format!("killed by {}", name), signal_description(name)),
+        };
+        *by_pid.entry((traced.pid, name)).or_default() += 1;
+        description
+            .map(|description| vec![(traced.end, description)])
+            .unwrap_or_default()
+    });
+
+    // every error or signal is counted per pid; without -f, the pids are not known,
+    // so only the signals are left to count, under "-"
+    if by_pid.keys().all(|(pid, _)| pid.is_none()) {
+        by_pid.retain(|(_, name), _| is_signal(name));
+    }
+    if !args.summary && (!by_syscall.is_empty() || !by_pid.is_empty()) {
+        println!();
+    }
+    if !by_syscall.is_empty() {
+        let rows = sorted(by_syscall)
+            .into_iter()
+            .map(|((syscall, name), count)| {
+                let description = error_description(&name);
+                [syscall, name, count.to_string(), description]
+            })
+            .collect();
+        create_table(["syscall", "error", "count", "description"], rows);
+    }
+    if !by_pid.is_empty() {
+        let rows = sorted(by_pid)
+            .into_iter()
+            .map(|((pid, name), count)| {
+                let pid = pid.map_or_else(|| "-".to_owned(), |pid| pid.to_string());
+                let description = if is_signal(&name) {
+                    let signal = name.strip_prefix("killed by ").unwrap_or(&name);
+                    signal_description(signal).unwrap_or_else(|| "Unknown signal".to_owned())
+                } else {
+                    error_description(&name)
+                };
+                [pid, name, count.to_string(), description]
+            })
+            .collect();
+        create_table(["pid", "error or signal", "count", "description"], rows);
+    }
+}
+
+/// Describes an error, with the same fallback in both tables (strace also reports the
+/// kernel-internal ones, e.g. ERESTARTSYS)
+fn error_description(name: &str) -> String {
+    ErrnoDesc::from_name(name)
+        .map(|desc| desc.manpages_description())
+        .unwrap_or_else(|| "Unknown error".to_owned())
+}
+
+/// Whether a name counted per pid is a signal (e.g. "SIGCHLD" or "killed by SIGPIPE")
+fn is_signal(name: &str) -> bool {
+    name.strip_prefix("killed by ").unwrap_or(name).starts_with("SIG")
+}
+
+fn signal_description(name: &str) -> Option<String> {
+    SignalDesc::from_name(name).map(|desc| desc.manpages_description())
+}
+
+/// Orders the counts by their syscall (or pid), the most frequent names first
+fn sorted<K: Ord>(counts: Counts<K>) -> Vec<((K, String), usize)> {
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|((a, a_name), a_count), ((b, b_name), b_count)| {
+        a.cmp(b).then(b_count.cmp(a_count)).then(a_name.cmp(b_name))
+    });
+    counts
+}
+
+fn create_table(header: [&str; 4], rows: Vec<[String; 4]>) {
+    let desc_len = rows.iter().map(|row| row[3].len()).max().unwrap_or(0);
+    let mut table = Table::new(vec![
+        Border::Double.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::Cells {
+            width: CellSize::Fixed(desc_len.min(80)),
+        },
+        Border::Double.into(),
+    ]);
+
+    table.append_row(Border::Double.into());
+    table.append_row(Row::flexible_height(
+        header.iter().map(|title| Cell::left(*title)).collect(),
+    ));
+    table.append_row(Border::Single.into());
+
+    for [key, name, count, description] in rows {
+        table.append_row(Row::flexible_height(vec![
+            Cell::left(key),
+            Cell {
+                value: name,
+                align: Align::Left,
+                style: Style::default().bold(),
+            },
+            Cell::right(count),
+            Cell::left(description),
+        ]));
+    }
+
+    table.append_row(Border::Double.into());
+    print!("{}", table);
+}
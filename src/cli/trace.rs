@@ -0,0 +1,85 @@
+//! The `--trace` mode of `errno`: runs a command and summarizes the system calls that failed.
+
+use crate::errno::ErrnoDesc;
+use box_drawing_table::{ansi_term::Style, Align, Border, Cell, Column, Row, Table};
+
+/// Traces the command, prints the failures to the standard error and exits like the command did
+pub(super) fn run(command: &[String], simple: bool) -> ! {
+    let (program, args) = command.split_first().expect("clap requires a command");
+    let mut report = match crate::trace::run(program, args) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("errno: cannot trace {}: {}", program, err);
+            super::exit_not_started(&err);
+        }
+    };
+    report
+        .failures
+        .sort_by_key(|failure| std::cmp::Reverse(failure.count));
+
+    let rows: Vec<[String; 4]> = report
+        .failures
+        .into_iter()
+        .map(|failure| {
+            // the compat system calls (e.g. of an i386 binary on x86_64) are numbered differently
+            let syscall = failure
+                .name()
+                .map(str::to_owned)
+                .unwrap_or_else(|| format!("syscall_{}", failure.syscall));
+            // e.g. ERESTARTSYS, which the kernel only uses internally
+            let error = ErrnoDesc::from_number(failure.errno)
+                .map(|desc| desc.name().to_owned())
+                .unwrap_or_else(|| failure.errno.to_string());
+            [syscall, error, failure.count.to_string(), failure.sample]
+        })
+        .collect();
+
+    if simple {
+        for row in &rows {
+            eprintln!("{}", row.join(" "));
+        }
+    } else if !rows.is_empty() {
+        eprint!("{}", create_table(rows));
+    }
+
+    super::exit_like(report.status);
+}
+
+fn create_table(rows: Vec<[String; 4]>) -> Table {
+    let mut table = Table::new(vec![
+        Border::Double.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Single.into(),
+        Column::flexible_width(),
+        Border::Double.into(),
+    ]);
+
+    table.append_row(Border::Double.into());
+    table.append_row(Row::flexible_height(vec![
+        Cell::left("syscall"),
+        Cell::left("error"),
+        Cell::left("count"),
+        Cell::left("sample argument"),
+    ]));
+    table.append_row(Border::Single.into());
+
+    for [syscall, error, count, sample] in rows {
+        table.append_row(Row::flexible_height(vec![
+            Cell::left(syscall),
+            Cell {
+                value: error,
+                align: Align::Left,
+                style: Style::default().bold(),
+            },
+            Cell::right(count),
+            Cell::left(sample),
+        ]));
+    }
+
+    table.append_row(Border::Double.into());
+    table
+}
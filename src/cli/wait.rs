@@ -0,0 +1,151 @@
+//! The `--run` and `--wait-status` modes of `signal`: explain how a command ended.
+
+use crate::signal::SignalDesc;
+use crate::sysexits;
+use libc::c_int;
+use std::os::unix::process::ExitStatusExt;
+use std::process::Command;
+
+/// Runs the command, explains on the standard error how it ended, and exits like it did
+pub(super) fn run(command: &[String], libc: bool) -> ! {
+    let (program, args) = command.split_first().expect("clap requires a command");
+    let mut child = match Command::new(program).args(args).spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            eprintln!("signal: cannot run {}: {}", program, err);
+            super::exit_not_started(&err);
+        }
+    };
+    // like system(3), leave the interrupts from the terminal to the command
+    unsafe {
+        libc::signal(libc::SIGINT, libc::SIG_IGN);
+        libc::signal(libc::SIGQUIT, libc::SIG_IGN);
+    }
+    let status = match child.wait() {
+        Ok(status) => status.into_raw(),
+        Err(err) => {
+            eprintln!("signal: cannot wait for {}: {}", program, err);
+            std::process::exit(1);
+        }
+    };
+
+    eprintln!("{} {}", program, explain(status, libc));
+    super::exit_like(status);
+}
+
+/// Explains a wait status as returned by waitpid(2) (e.g. "killed by SIGKILL (9): Kill signal")
+pub(super) fn explain(status: c_int, libc: bool) -> String {
+    if libc::WIFEXITED(status) {
+        explain_exit(libc::WEXITSTATUS(status), libc)
+    } else if libc::WIFSIGNALED(status) {
+        let core = if libc::WCOREDUMP(status) {
+            "core dumped"
+        } else {
+            "no core dumped"
+        };
+        format!(
+            "killed by {}; {}",
+            signal(libc::WTERMSIG(status), libc),
+            core
+        )
+    } else if libc::WIFSTOPPED(status) {
+        explain_stop(status, libc)
+    } else if libc::WIFCONTINUED(status) {
+        "continued by SIGCONT".to_owned()
+    } else {
+        format!("unknown wait status {:#x}", status)
+    }
+}
+
+/// The ptrace events reported in bits 16 to 23 of the status of a stop by SIGTRAP
+const PTRACE_EVENTS: &[(c_int, &str)] = &[
+    (libc::PTRACE_EVENT_FORK, "PTRACE_EVENT_FORK"),
+    (libc::PTRACE_EVENT_VFORK, "PTRACE_EVENT_VFORK"),
+    (libc::PTRACE_EVENT_CLONE, "PTRACE_EVENT_CLONE"),
+    (libc::PTRACE_EVENT_EXEC, "PTRACE_EVENT_EXEC"),
+    (libc::PTRACE_EVENT_VFORK_DONE, "PTRACE_EVENT_VFORK_DONE"),
+    (libc::PTRACE_EVENT_EXIT, "PTRACE_EVENT_EXIT"),
+    (libc::PTRACE_EVENT_SECCOMP, "PTRACE_EVENT_SECCOMP"),
+    (PTRACE_EVENT_STOP, "PTRACE_EVENT_STOP"),
+];
+
+// not in older versions of libc
+const PTRACE_EVENT_STOP: c_int = 128;
+
+fn explain_stop(status: c_int, libc: bool) -> String {
+    let signal_number = libc::WSTOPSIG(status);
+    let event = (status >> 16) & 0xff;
+    if signal_number == libc::SIGTRAP | 0x80 {
+        return "stopped at a system call under ptrace with PTRACE_O_TRACESYSGOOD (SIGTRAP | 0x80)"
+            .to_owned();
+    }
+    let stopped = format!("stopped by {}", signal(signal_number, libc));
+    if event == 0 {
+        return stopped;
+    }
+    match PTRACE_EVENTS.iter().find(|(number, _)| *number == event) {
+        Some((_, name)) => format!("{} for the ptrace event {}", stopped, name),
+        None => format!("{} for the unknown ptrace event {}", stopped, event),
+    }
+}
+
+/// Explains each wait status given in decimal or in hexadecimal (e.g. "134" or "0x8b"),
+/// exiting with 1 after the others if any is invalid
+pub(super) fn decode(statuses: &[String], libc: bool) {
+    let mut invalid = false;
+    for query in statuses {
+        let parsed = match query
+            .strip_prefix("0x")
+            .or_else(|| query.strip_prefix("0X"))
+        {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => query.parse(),
+        };
+        match parsed {
+            Ok(status) => println!("{}: {}", query, explain(status as c_int, libc)),
+            Err(_) => {
+                eprintln!("signal: invalid wait status '{}'", query);
+                invalid = true;
+            }
+        }
+    }
+    if invalid {
+        std::process::exit(1);
+    }
+}
+
+fn explain_exit(code: c_int, libc: bool) -> String {
+    let meaning = if let Some((name, explanation)) = sysexits::lookup(code) {
+        Some(format!("{}: {}", name, explanation))
+    } else if code == 126 {
+        Some("a shell could not execute the command".to_owned())
+    } else if code == 127 {
+        Some("a shell could not find the command".to_owned())
+    } else if code > 128 && SignalDesc::from_number(code - 128).is_some() {
+        Some(format!(
+            "shells use it for a command killed by {}",
+            signal(code - 128, libc)
+        ))
+    } else {
+        None
+    };
+    match meaning {
+        Some(meaning) => format!("exited with status {} ({})", code, meaning),
+        None => format!("exited with status {}", code),
+    }
+}
+
+/// Names and describes a signal (e.g. "SIGKILL (9): Kill signal")
+fn signal(number: c_int, libc: bool) -> String {
+    match SignalDesc::from_number(number) {
+        Some(desc) => {
+            let description = if libc {
+                desc.libc_description()
+            } else {
+                desc.manpages_description()
+            };
+            format!("{} ({}): {}", desc.name(), number, description)
+        }
+        None => format!("the unknown signal {}", number),
+    }
+}
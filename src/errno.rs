@@ -1,554 +1,251 @@
-#![cfg(all(
-    target_os = "linux",
-    any(
-        target_arch = "x86_64",
-        target_arch = "x86",
-        target_arch = "arm",
-        target_arch = "aarch64"
-    )
-))]
-
-use box_drawing_table::{ansi_term::Style, Align, Border, Cell, CellSize, Column, Row, Table};
-use clap::Parser;
+use crate::arch::Arch;
+use crate::os::Os;
 use libc::{c_int, strerror};
 use std::ffi::CStr;
 
-// NOTE: based on Linux man-pages 6.01
+mod os;
+mod table;
 
-/// A simple utility to look up Linux error numbers (errno)
-#[derive(Debug, Parser)]
-#[clap(author, version, about, long_about = None)]
-struct Args {
-    #[clap(value_parser)]
-    /// errno value (e.g. "2"),
-    /// or symbolic name (e.g. "ENOENT")
-    query: Vec<String>,
+/// A row of the error table
+struct Entry {
+    name: &'static str,
+    /// The number on the generic architectures (i.e. the host)
+    number: c_int,
+    // the numbers on the other architectures, from arch/*/include/uapi/asm/errno.h of Linux
+    alpha: c_int,
+    mips: c_int,
+    powerpc: c_int,
+    sparc: c_int,
+    /// The name this one is a synonym for; synonyms are only returned by number
+    /// where they have a number of their own (e.g. EDEADLOCK on MIPS)
+    alias_of: Option<&'static str>,
+    description: &'static str,
+}
 
-    #[clap(long, default_value_t = false)]
-    /// Disable pretty-printing
-    simple: bool,
+/// A row of the error table of another system: name, number, and the description
+/// where Linux has no such name or gives it another meaning
+type Foreign = (&'static str, c_int, Option<&'static str>);
 
-    #[clap(short, long, default_value_t = false)]
-    /// List all errors
-    list: bool,
+/// Returns the error table of another system
+fn foreign(os: Os) -> &'static [Foreign] {
+    match os {
+        Os::Linux => &[],
+        Os::Freebsd => os::FREEBSD,
+        Os::Macos => os::MACOS,
+        Os::Openbsd => os::OPENBSD,
+        Os::Illumos => os::ILLUMOS,
+        Os::Wasi => os::WASI,
+    }
+}
 
-    #[clap(long, default_value_t = false)]
-    /// Display the description using strerror(3)
-    libc: bool,
+/// Returns the names of all known errors
+pub fn list() -> Vec<String> {
+    list_in(Os::Linux)
 }
 
-fn main() {
-    let args = Args::parse();
+/// Returns the names of all known errors of the system
+pub fn list_in(os: Os) -> Vec<String> {
+    all_in(os).map(|desc| desc.name().to_owned()).collect()
+}
 
-    let mut rows = Vec::new();
-    let mut desc_len = 0_usize;
+/// Searches the man-pages and strerror(3) descriptions of all errors for the words
+///
+/// Every error matching a word is returned, those matching the most words first,
+/// and among them those containing the whole phrase first.
+pub fn search<S: AsRef<str>>(words: &[S]) -> Vec<ErrnoDesc> {
+    search_in(Os::Linux, words)
+}
 
-    let queries = if args.list { list() } else { args.query };
-    for q in queries {
-        let sigdesc = if let Ok(num) = q.parse::<c_int>() {
-            ErrnoDesc::from_number(num).ok_or(num)
-        } else {
-            Ok(ErrnoDesc::from_name(q.clone()))
-        };
+/// Searches the descriptions of all errors of the system for the words, like [`search`]
+pub fn search_in<S: AsRef<str>>(os: Os, words: &[S]) -> Vec<ErrnoDesc> {
+    crate::search::search(words, all_in(os), |desc| {
+        vec![desc.manpages_description(), desc.libc_description()]
+    })
+}
 
-        let name: String;
-        let number: String;
-        let description: String;
-        match sigdesc {
-            Ok(sigdesc) => {
-                name = sigdesc.name().to_string();
-                number = sigdesc
-                    .number()
-                    .map(|n| n.to_string())
-                    .unwrap_or_else(|| "-".to_owned());
+/// Returns the known names closest to a misspelled one (e.g. ENOENT for "ENOENTT")
+pub fn suggest(name: &str) -> Vec<String> {
+    suggest_in(Os::Linux, name)
+}
 
-                if args.libc {
-                    description = sigdesc.libc_descripton();
-                } else {
-                    description = sigdesc.manpages_description();
-                }
-            }
-            Err(num) => {
-                name = "-".to_owned();
-                number = num.to_string();
-                description = "Unknown error".to_owned();
-            }
-        }
+/// Returns the names of the system closest to a misspelled one
+pub fn suggest_in(os: Os, name: &str) -> Vec<String> {
+    crate::search::suggest(name, list_in(os))
+}
 
-        if args.simple {
-            println!("{} {} {}", name, number, description);
-        } else {
-            desc_len = desc_len.max(description.len());
+/// Returns every error of the system
+fn all_in(os: Os) -> Box<dyn Iterator<Item = ErrnoDesc>> {
+    match os {
+        Os::Linux => Box::new(table::TABLE.iter().map(ErrnoDesc::new)),
+        _ => Box::new(
+            foreign(os)
+                .iter()
+                .map(move |row| ErrnoDesc::foreign(os, row)),
+        ),
+    }
+}
 
-            rows.push(Row::flexible_height(vec![
-                Cell {
-                    value: name,
-                    align: Align::Left,
-                    style: Style::default().bold(),
-                },
-                Cell::left(number),
-                Cell::left(description),
-            ]));
-        }
+/// A Linux error, identified by its symbolic name
+///
+/// Numbers are those of the generic architectures unless another one is chosen with [`ErrnoDesc::on`],
+/// or those of another system for the errors looked up with [`ErrnoDesc::from_name_in`]
+/// and [`ErrnoDesc::from_number_in`].
+pub struct ErrnoDesc {
+    /// The Linux entry of the same name, if any
+    entry: Option<&'static Entry>,
+    /// The entry of the other system, if the error is not a Linux one
+    foreign: Option<&'static Foreign>,
+    os: Os,
+    arch: Arch,
+}
+
+impl ErrnoDesc {
+    /// Looks up the error by its symbolic name (e.g. "ENOENT")
+    ///
+    /// Returns `None` for unknown names; see [`suggest`] for the closest known ones.
+    pub fn from_name(name: &str) -> Option<Self> {
+        table::TABLE
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(Self::new)
     }
 
-    if !args.simple && !rows.is_empty() {
-        create_table(rows, desc_len.min(80));
+    /// Looks up the error by its symbolic name on the system
+    pub fn from_name_in(os: Os, name: &str) -> Option<Self> {
+        all_in(os).find(|desc| desc.name() == name)
     }
-}
 
-fn create_table(rows: Vec<Row>, desc_len: usize) {
-    let mut table = Table::new(vec![
-        Border::Double.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::Cells {
-            width: CellSize::Fixed(desc_len),
-        },
-        Border::Double.into(),
-    ]);
+    /// Looks up the error corresponding to the given errno value
+    pub fn from_number(errno: c_int) -> Option<Self> {
+        Self::from_number_on(Arch::Generic, errno)
+    }
 
-    table.append_row(Border::Double.into());
-    table.append_row(Row::flexible_height(vec![
-        Cell::left("name"),
-        Cell::left("number"),
-        Cell::left("description"),
-    ]));
-    table.append_row(Border::Single.into());
+    /// Looks up the error corresponding to the given errno value on the architecture
+    pub fn from_number_on(arch: Arch, errno: c_int) -> Option<Self> {
+        Self::preferred(
+            table::TABLE.iter().map(|entry| Self::new(entry).on(arch)),
+            errno,
+        )
+    }
 
-    for r in rows {
-        table.append_row(r);
+    /// Looks up the error corresponding to the given errno value on the system
+    pub fn from_number_in(os: Os, errno: c_int) -> Option<Self> {
+        Self::preferred(all_in(os), errno)
     }
 
-    table.append_row(Border::Double.into());
+    /// Returns the first error with the number, preferring those which are not synonyms
+    fn preferred(descs: impl Iterator<Item = Self>, errno: c_int) -> Option<Self> {
+        let mut found = descs.filter(|desc| desc.number() == Some(errno));
+        let first = found.next()?;
+        if first.alias_of().is_none() {
+            return Some(first);
+        }
+        found.find(|desc| desc.alias_of().is_none()).or(Some(first))
+    }
 
-    print!("{}", table);
-}
+    fn new(entry: &'static Entry) -> Self {
+        Self {
+            entry: Some(entry),
+            foreign: None,
+            os: Os::Linux,
+            arch: Arch::Generic,
+        }
+    }
 
-fn list() -> Vec<String> {
-    [
-        "E2BIG",
-        "EACCES",
-        "EADDRINUSE",
-        "EADDRNOTAVAIL",
-        "EAFNOSUPPORT",
-        "EAGAIN",
-        "EALREADY",
-        "EBADE",
-        "EBADF",
-        "EBADFD",
-        "EBADMSG",
-        "EBADR",
-        "EBADRQC",
-        "EBADSLT",
-        "EBUSY",
-        "ECANCELED",
-        "ECHILD",
-        "ECHRNG",
-        "ECOMM",
-        "ECONNABORTED",
-        "ECONNREFUSED",
-        "ECONNRESET",
-        "EDEADLK",
-        "EDEADLOCK",
-        "EDESTADDRREQ",
-        "EDOM",
-        "EDQUOT",
-        "EEXIST",
-        "EFAULT",
-        "EFBIG",
-        "EHOSTDOWN",
-        "EHOSTUNREACH",
-        "EHWPOISON",
-        "EIDRM",
-        "EILSEQ",
-        "EINPROGRESS",
-        "EINTR",
-        "EINVAL",
-        "EIO",
-        "EISCONN",
-        "EISDIR",
-        "EISNAM",
-        "EKEYEXPIRED",
-        "EKEYREJECTED",
-        "EKEYREVOKED",
-        "EL2HLT",
-        "EL2NSYNC",
-        "EL3HLT",
-        "EL3RST",
-        "ELIBACC",
-        "ELIBBAD",
-        "ELIBMAX",
-        "ELIBSCN",
-        "ELIBEXEC",
-        "ELNRNG",
-        "ELOOP",
-        "EMEDIUMTYPE",
-        "EMFILE",
-        "EMLINK",
-        "EMSGSIZE",
-        "EMULTIHOP",
-        "ENAMETOOLONG",
-        "ENETDOWN",
-        "ENETRESET",
-        "ENETUNREACH",
-        "ENFILE",
-        "ENOANO",
-        "ENOBUFS",
-        "ENODATA",
-        "ENODEV",
-        "ENOENT",
-        "ENOEXEC",
-        "ENOKEY",
-        "ENOLCK",
-        "ENOLINK",
-        "ENOMEDIUM",
-        "ENOMEM",
-        "ENOMSG",
-        "ENONET",
-        "ENOPKG",
-        "ENOPROTOOPT",
-        "ENOSPC",
-        "ENOSR",
-        "ENOSTR",
-        "ENOSYS",
-        "ENOTBLK",
-        "ENOTCONN",
-        "ENOTDIR",
-        "ENOTEMPTY",
-        "ENOTRECOVERABLE",
-        "ENOTSOCK",
-        "ENOTSUP",
-        "ENOTTY",
-        "ENOTUNIQ",
-        "ENXIO",
-        "EOPNOTSUPP",
-        "EOVERFLOW",
-        "EOWNERDEAD",
-        "EPERM",
-        "EPFNOSUPPORT",
-        "EPIPE",
-        "EPROTO",
-        "EPROTONOSUPPORT",
-        "EPROTOTYPE",
-        "ERANGE",
-        "EREMCHG",
-        "EREMOTE",
-        "EREMOTEIO",
-        "ERESTART",
-        "ERFKILL",
-        "EROFS",
-        "ESHUTDOWN",
-        "ESPIPE",
-        "ESOCKTNOSUPPORT",
-        "ESRCH",
-        "ESTALE",
-        "ESTRPIPE",
-        "ETIME",
-        "ETIMEDOUT",
-        "ETOOMANYREFS",
-        "ETXTBSY",
-        "EUCLEAN",
-        "EUNATCH",
-        "EUSERS",
-        "EWOULDBLOCK",
-        "EXDEV",
-        "EXFULL",
-    ]
-    .iter()
-    .map(|name| name.to_string())
-    .collect()
-}
+    fn foreign(os: Os, row: &'static Foreign) -> Self {
+        Self {
+            entry: table::TABLE.iter().find(|entry| entry.name == row.0),
+            foreign: Some(row),
+            os,
+            arch: Arch::Generic,
+        }
+    }
 
-struct ErrnoDesc {
-    name: String,
-}
+    /// Returns the same error with the numbers of another architecture
+    ///
+    /// Architectures only matter on Linux; the errors of other systems are returned unchanged.
+    pub fn on(&self, arch: Arch) -> Self {
+        Self {
+            entry: self.entry,
+            foreign: self.foreign,
+            os: self.os,
+            arch: if self.foreign.is_some() {
+                Arch::Generic
+            } else {
+                arch
+            },
+        }
+    }
 
-impl ErrnoDesc {
-    fn from_name(name: String) -> Self {
-        Self { name }
+    /// Returns the system of the numbers
+    pub fn os(&self) -> Os {
+        self.os
+    }
+
+    /// Returns the architecture of the numbers
+    pub fn arch(&self) -> Arch {
+        self.arch
     }
 
-    fn from_number(errno: c_int) -> Option<Self> {
-        let name = match errno {
-            libc::E2BIG => "E2BIG",
-            libc::EACCES => "EACCES",
-            libc::EADDRINUSE => "EADDRINUSE",
-            libc::EADDRNOTAVAIL => "EADDRNOTAVAIL",
-            libc::EAFNOSUPPORT => "EAFNOSUPPORT",
-            libc::EAGAIN => "EAGAIN",
-            libc::EALREADY => "EALREADY",
-            libc::EBADE => "EBADE",
-            libc::EBADF => "EBADF",
-            libc::EBADFD => "EBADFD",
-            libc::EBADMSG => "EBADMSG",
-            libc::EBADR => "EBADR",
-            libc::EBADRQC => "EBADRQC",
-            libc::EBADSLT => "EBADSLT",
-            libc::EBUSY => "EBUSY",
-            libc::ECANCELED => "ECANCELED",
-            libc::ECHILD => "ECHILD",
-            libc::ECHRNG => "ECHRNG",
-            libc::ECOMM => "ECOMM",
-            libc::ECONNABORTED => "ECONNABORTED",
-            libc::ECONNREFUSED => "ECONNREFUSED",
-            libc::ECONNRESET => "ECONNRESET",
-            libc::EDEADLK => "EDEADLK",
-            // libc::EDEADLOCK => "EDEADLOCK",
-            libc::EDESTADDRREQ => "EDESTADDRREQ",
-            libc::EDOM => "EDOM",
-            libc::EDQUOT => "EDQUOT",
-            libc::EEXIST => "EEXIST",
-            libc::EFAULT => "EFAULT",
-            libc::EFBIG => "EFBIG",
-            libc::EHOSTDOWN => "EHOSTDOWN",
-            libc::EHOSTUNREACH => "EHOSTUNREACH",
-            libc::EHWPOISON => "EHWPOISON",
-            libc::EIDRM => "EIDRM",
-            libc::EILSEQ => "EILSEQ",
-            libc::EINPROGRESS => "EINPROGRESS",
-            libc::EINTR => "EINTR",
-            libc::EINVAL => "EINVAL",
-            libc::EIO => "EIO",
-            libc::EISCONN => "EISCONN",
-            libc::EISDIR => "EISDIR",
-            libc::EISNAM => "EISNAM",
-            libc::EKEYEXPIRED => "EKEYEXPIRED",
-            libc::EKEYREJECTED => "EKEYREJECTED",
-            libc::EKEYREVOKED => "EKEYREVOKED",
-            libc::EL2HLT => "EL2HLT",
-            libc::EL2NSYNC => "EL2NSYNC",
-            libc::EL3HLT => "EL3HLT",
-            libc::EL3RST => "EL3RST",
-            libc::ELIBACC => "ELIBACC",
-            libc::ELIBBAD => "ELIBBAD",
-            libc::ELIBMAX => "ELIBMAX",
-            libc::ELIBSCN => "ELIBSCN",
-            libc::ELIBEXEC => "ELIBEXEC",
-            libc::ELNRNG => "ELNRNG",
-            libc::ELOOP => "ELOOP",
-            libc::EMEDIUMTYPE => "EMEDIUMTYPE",
-            libc::EMFILE => "EMFILE",
-            libc::EMLINK => "EMLINK",
-            libc::EMSGSIZE => "EMSGSIZE",
-            libc::EMULTIHOP => "EMULTIHOP",
-            libc::ENAMETOOLONG => "ENAMETOOLONG",
-            libc::ENETDOWN => "ENETDOWN",
-            libc::ENETRESET => "ENETRESET",
-            libc::ENETUNREACH => "ENETUNREACH",
-            libc::ENFILE => "ENFILE",
-            libc::ENOANO => "ENOANO",
-            libc::ENOBUFS => "ENOBUFS",
-            libc::ENODATA => "ENODATA",
-            libc::ENODEV => "ENODEV",
-            libc::ENOENT => "ENOENT",
-            libc::ENOEXEC => "ENOEXEC",
-            libc::ENOKEY => "ENOKEY",
-            libc::ENOLCK => "ENOLCK",
-            libc::ENOLINK => "ENOLINK",
-            libc::ENOMEDIUM => "ENOMEDIUM",
-            libc::ENOMEM => "ENOMEM",
-            libc::ENOMSG => "ENOMSG",
-            libc::ENONET => "ENONET",
-            libc::ENOPKG => "ENOPKG",
-            libc::ENOPROTOOPT => "ENOPROTOOPT",
-            libc::ENOSPC => "ENOSPC",
-            libc::ENOSR => "ENOSR",
-            libc::ENOSTR => "ENOSTR",
-            libc::ENOSYS => "ENOSYS",
-            libc::ENOTBLK => "ENOTBLK",
-            libc::ENOTCONN => "ENOTCONN",
-            libc::ENOTDIR => "ENOTDIR",
-            libc::ENOTEMPTY => "ENOTEMPTY",
-            libc::ENOTRECOVERABLE => "ENOTRECOVERABLE",
-            libc::ENOTSOCK => "ENOTSOCK",
-            libc::ENOTSUP => "ENOTSUP",
-            libc::ENOTTY => "ENOTTY",
-            libc::ENOTUNIQ => "ENOTUNIQ",
-            libc::ENXIO => "ENXIO",
-            // libc::EOPNOTSUPP => "EOPNOTSUPP",
-            libc::EOVERFLOW => "EOVERFLOW",
-            libc::EOWNERDEAD => "EOWNERDEAD",
-            libc::EPERM => "EPERM",
-            libc::EPFNOSUPPORT => "EPFNOSUPPORT",
-            libc::EPIPE => "EPIPE",
-            libc::EPROTO => "EPROTO",
-            libc::EPROTONOSUPPORT => "EPROTONOSUPPORT",
-            libc::EPROTOTYPE => "EPROTOTYPE",
-            libc::ERANGE => "ERANGE",
-            libc::EREMCHG => "EREMCHG",
-            libc::EREMOTE => "EREMOTE",
-            libc::EREMOTEIO => "EREMOTEIO",
-            libc::ERESTART => "ERESTART",
-            libc::ERFKILL => "ERFKILL",
-            libc::EROFS => "EROFS",
-            libc::ESHUTDOWN => "ESHUTDOWN",
-            libc::ESPIPE => "ESPIPE",
-            libc::ESOCKTNOSUPPORT => "ESOCKTNOSUPPORT",
-            libc::ESRCH => "ESRCH",
-            libc::ESTALE => "ESTALE",
-            libc::ESTRPIPE => "ESTRPIPE",
-            libc::ETIME => "ETIME",
-            libc::ETIMEDOUT => "ETIMEDOUT",
-            libc::ETOOMANYREFS => "ETOOMANYREFS",
-            libc::ETXTBSY => "ETXTBSY",
-            libc::EUCLEAN => "EUCLEAN",
-            libc::EUNATCH => "EUNATCH",
-            libc::EUSERS => "EUSERS",
-            // libc::EWOULDBLOCK => "EWOULDBLOCK",
-            libc::EXDEV => "EXDEV",
-            libc::EXFULL => "EXFULL",
-            _ => None?,
+    /// Returns the symbolic name
+    pub fn name(&self) -> &str {
+        match (self.foreign, self.entry) {
+            (Some(row), _) => row.0,
+            (None, Some(entry)) => entry.name,
+            (None, None) => unreachable!("an error comes from one of the tables"),
         }
-        .to_owned();
-        Some(Self { name })
     }
 
-    fn name(&self) -> &str {
-        self.name.as_str()
+    /// Returns the errno value, or `None` if it is unknown or not used on Linux
+    pub fn number(&self) -> Option<c_int> {
+        let entry = match (self.foreign, self.entry) {
+            (Some(row), _) => return Some(row.1),
+            (None, entry) => entry?,
+        };
+        let number = match self.arch {
+            Arch::Generic => entry.number,
+            Arch::Alpha => entry.alpha,
+            Arch::Mips => entry.mips,
+            Arch::Powerpc => entry.powerpc,
+            Arch::Sparc => entry.sparc,
+        };
+        Some(number)
     }
 
-    fn number(&self) -> Option<c_int> {
-        let num = match self.name.as_str() {
-            "E2BIG" => libc::E2BIG,
-            "EACCES" => libc::EACCES,
-            "EADDRINUSE" => libc::EADDRINUSE,
-            "EADDRNOTAVAIL" => libc::EADDRNOTAVAIL,
-            "EAFNOSUPPORT" => libc::EAFNOSUPPORT,
-            "EAGAIN" => libc::EAGAIN,
-            "EALREADY" => libc::EALREADY,
-            "EBADE" => libc::EBADE,
-            "EBADF" => libc::EBADF,
-            "EBADFD" => libc::EBADFD,
-            "EBADMSG" => libc::EBADMSG,
-            "EBADR" => libc::EBADR,
-            "EBADRQC" => libc::EBADRQC,
-            "EBADSLT" => libc::EBADSLT,
-            "EBUSY" => libc::EBUSY,
-            "ECANCELED" => libc::ECANCELED,
-            "ECHILD" => libc::ECHILD,
-            "ECHRNG" => libc::ECHRNG,
-            "ECOMM" => libc::ECOMM,
-            "ECONNABORTED" => libc::ECONNABORTED,
-            "ECONNREFUSED" => libc::ECONNREFUSED,
-            "ECONNRESET" => libc::ECONNRESET,
-            "EDEADLK" => libc::EDEADLK,
-            "EDEADLOCK" => libc::EDEADLOCK,
-            "EDESTADDRREQ" => libc::EDESTADDRREQ,
-            "EDOM" => libc::EDOM,
-            "EDQUOT" => libc::EDQUOT,
-            "EEXIST" => libc::EEXIST,
-            "EFAULT" => libc::EFAULT,
-            "EFBIG" => libc::EFBIG,
-            "EHOSTDOWN" => libc::EHOSTDOWN,
-            "EHOSTUNREACH" => libc::EHOSTUNREACH,
-            "EHWPOISON" => libc::EHWPOISON,
-            "EIDRM" => libc::EIDRM,
-            "EILSEQ" => libc::EILSEQ,
-            "EINPROGRESS" => libc::EINPROGRESS,
-            "EINTR" => libc::EINTR,
-            "EINVAL" => libc::EINVAL,
-            "EIO" => libc::EIO,
-            "EISCONN" => libc::EISCONN,
-            "EISDIR" => libc::EISDIR,
-            "EISNAM" => libc::EISNAM,
-            "EKEYEXPIRED" => libc::EKEYEXPIRED,
-            "EKEYREJECTED" => libc::EKEYREJECTED,
-            "EKEYREVOKED" => libc::EKEYREVOKED,
-            "EL2HLT" => libc::EL2HLT,
-            "EL2NSYNC" => libc::EL2NSYNC,
-            "EL3HLT" => libc::EL3HLT,
-            "EL3RST" => libc::EL3RST,
-            "ELIBACC" => libc::ELIBACC,
-            "ELIBBAD" => libc::ELIBBAD,
-            "ELIBMAX" => libc::ELIBMAX,
-            "ELIBSCN" => libc::ELIBSCN,
-            "ELIBEXEC" => libc::ELIBEXEC,
-            "ELNRNG" => libc::ELNRNG,
-            "ELOOP" => libc::ELOOP,
-            "EMEDIUMTYPE" => libc::EMEDIUMTYPE,
-            "EMFILE" => libc::EMFILE,
-            "EMLINK" => libc::EMLINK,
-            "EMSGSIZE" => libc::EMSGSIZE,
-            "EMULTIHOP" => libc::EMULTIHOP,
-            "ENAMETOOLONG" => libc::ENAMETOOLONG,
-            "ENETDOWN" => libc::ENETDOWN,
-            "ENETRESET" => libc::ENETRESET,
-            "ENETUNREACH" => libc::ENETUNREACH,
-            "ENFILE" => libc::ENFILE,
-            "ENOANO" => libc::ENOANO,
-            "ENOBUFS" => libc::ENOBUFS,
-            "ENODATA" => libc::ENODATA,
-            "ENODEV" => libc::ENODEV,
-            "ENOENT" => libc::ENOENT,
-            "ENOEXEC" => libc::ENOEXEC,
-            "ENOKEY" => libc::ENOKEY,
-            "ENOLCK" => libc::ENOLCK,
-            "ENOLINK" => libc::ENOLINK,
-            "ENOMEDIUM" => libc::ENOMEDIUM,
-            "ENOMEM" => libc::ENOMEM,
-            "ENOMSG" => libc::ENOMSG,
-            "ENONET" => libc::ENONET,
-            "ENOPKG" => libc::ENOPKG,
-            "ENOPROTOOPT" => libc::ENOPROTOOPT,
-            "ENOSPC" => libc::ENOSPC,
-            "ENOSR" => libc::ENOSR,
-            "ENOSTR" => libc::ENOSTR,
-            "ENOSYS" => libc::ENOSYS,
-            "ENOTBLK" => libc::ENOTBLK,
-            "ENOTCONN" => libc::ENOTCONN,
-            "ENOTDIR" => libc::ENOTDIR,
-            "ENOTEMPTY" => libc::ENOTEMPTY,
-            "ENOTRECOVERABLE" => libc::ENOTRECOVERABLE,
-            "ENOTSOCK" => libc::ENOTSOCK,
-            "ENOTSUP" => libc::ENOTSUP,
-            "ENOTTY" => libc::ENOTTY,
-            "ENOTUNIQ" => libc::ENOTUNIQ,
-            "ENXIO" => libc::ENXIO,
-            "EOPNOTSUPP" => libc::EOPNOTSUPP,
-            "EOVERFLOW" => libc::EOVERFLOW,
-            "EOWNERDEAD" => libc::EOWNERDEAD,
-            "EPERM" => libc::EPERM,
-            "EPFNOSUPPORT" => libc::EPFNOSUPPORT,
-            "EPIPE" => libc::EPIPE,
-            "EPROTO" => libc::EPROTO,
-            "EPROTONOSUPPORT" => libc::EPROTONOSUPPORT,
-            "EPROTOTYPE" => libc::EPROTOTYPE,
-            "ERANGE" => libc::ERANGE,
-            "EREMCHG" => libc::EREMCHG,
-            "EREMOTE" => libc::EREMOTE,
-            "EREMOTEIO" => libc::EREMOTEIO,
-            "ERESTART" => libc::ERESTART,
-            "ERFKILL" => libc::ERFKILL,
-            "EROFS" => libc::EROFS,
-            "ESHUTDOWN" => libc::ESHUTDOWN,
-            "ESPIPE" => libc::ESPIPE,
-            "ESOCKTNOSUPPORT" => libc::ESOCKTNOSUPPORT,
-            "ESRCH" => libc::ESRCH,
-            "ESTALE" => libc::ESTALE,
-            "ESTRPIPE" => libc::ESTRPIPE,
-            "ETIME" => libc::ETIME,
-            "ETIMEDOUT" => libc::ETIMEDOUT,
-            "ETOOMANYREFS" => libc::ETOOMANYREFS,
-            "ETXTBSY" => libc::ETXTBSY,
-            "EUCLEAN" => libc::EUCLEAN,
-            "EUNATCH" => libc::EUNATCH,
-            "EUSERS" => libc::EUSERS,
-            "EWOULDBLOCK" => libc::EWOULDBLOCK,
-            "EXDEV" => libc::EXDEV,
-            "EXFULL" => libc::EXFULL,
-            _ => return None,
+    /// Returns the name this one is a synonym for (e.g. EAGAIN for EWOULDBLOCK)
+    pub fn alias_of(&self) -> Option<&str> {
+        let alias_of = self.entry?.alias_of?;
+        match self.foreign {
+            // only where the other system defines both with the same number
+            Some(row) => foreign(self.os)
+                .iter()
+                .any(|other| other.0 == alias_of && other.1 == row.1)
+                .then_some(alias_of),
+            None => Some(alias_of),
+        }
+    }
+
+    /// Returns the other names sharing the same errno value (e.g. EWOULDBLOCK for EAGAIN)
+    pub fn aliases(&self) -> Vec<String> {
+        let num = match self.number() {
+            Some(num) => num,
+            None => return Vec::new(),
         };
-        Some(num)
+        all_in(self.os)
+            .map(|desc| desc.on(self.arch))
+            .filter(|desc| desc.name() != self.name() && desc.number() == Some(num))
+            .map(|desc| desc.name().to_owned())
+            .collect()
     }
 
-    fn libc_descripton(&self) -> String {
-        let desc_ptr = self
-            .number()
-            .map(|num| unsafe { strerror(num) })
-            .unwrap_or(std::ptr::null_mut());
+    /// Returns the description provided by strerror(3) of the host
+    ///
+    /// The host knows the errors by their Linux numbers; errors Linux does not have are unknown to it.
+    pub fn libc_description(&self) -> String {
+        let desc_ptr = match self.entry {
+            Some(entry) => unsafe { strerror(entry.number) },
+            None => std::ptr::null_mut(),
+        };
 
         if desc_ptr.is_null() {
             "Unknown error".to_owned()
@@ -558,137 +255,17 @@ impl ErrnoDesc {
         }
     }
 
-    fn manpages_description(&self) -> String {
-        match self.name.as_str() {
-            "E2BIG" => "Argument list too long (POSIX.1-2001).",
-            "EACCES" => "Permission denied (POSIX.1-2001).",
-            "EADDRINUSE" => "Address already in use (POSIX.1-2001).",
-            "EADDRNOTAVAIL" => "Address not available (POSIX.1-2001).",
-            "EAFNOSUPPORT" => "Address family not supported (POSIX.1-2001).",
-            "EAGAIN" => "Resource temporarily unavailable (may be the same value as EWOULDBLOCK) (POSIX.1-2001).",
-            "EALREADY" => "Connection already in progress (POSIX.1-2001).",
-            "EBADE" => "Invalid exchange.",
-            "EBADF" => "Bad file descriptor (POSIX.1-2001).",
-            "EBADFD" => "File descriptor in bad state.",
-            "EBADMSG" => "Bad message (POSIX.1-2001).",
-            "EBADR" => "Invalid request descriptor.",
-            "EBADRQC" => "Invalid request code.",
-            "EBADSLT" => "Invalid slot.",
-            "EBUSY" => "Device or resource busy (POSIX.1-2001).",
-            "ECANCELED" => "Operation canceled (POSIX.1-2001).",
-            "ECHILD" => "No child processes (POSIX.1-2001).",
-            "ECHRNG" => "Channel number out of range.",
-            "ECOMM" => "Communication error on send.",
-            "ECONNABORTED" => "Connection aborted (POSIX.1-2001).",
-            "ECONNREFUSED" => "Connection refused (POSIX.1-2001).",
-            "ECONNRESET" => "Connection reset (POSIX.1-2001).",
-            "EDEADLK" => "Resource deadlock avoided (POSIX.1-2001).",
-            "EDEADLOCK" => "On most architectures, a synonym for EDEADLK. On some architectures (e.g., Linux MIPS, PowerPC, SPARC), it is a separate error code \"File locking deadlock error\".",
-            "EDESTADDRREQ" => "Destination address required (POSIX.1-2001).",
-            "EDOM" => "Mathematics argument out of domain of function (POSIX.1, C99).",
-            "EDQUOT" => "Disk quota exceeded (POSIX.1-2001).",
-            "EEXIST" => "File exists (POSIX.1-2001).",
-            "EFAULT" => "Bad address (POSIX.1-2001).",
-            "EFBIG" => "File too large (POSIX.1-2001).",
-            "EHOSTDOWN" => "Host is down.",
-            "EHOSTUNREACH" => "Host is unreachable (POSIX.1-2001).",
-            "EHWPOISON" => "Memory page has hardware error.",
-            "EIDRM" => "Identifier removed (POSIX.1-2001).",
-            "EILSEQ" => "Invalid or incomplete multibyte or wide character (POSIX.1, C99). The text shown here is the glibc error description; in POSIX.1, this error is described as \"Illegal byte sequence\".",
-            "EINPROGRESS" => "Operation in progress (POSIX.1-2001).",
-            "EINTR" => "Interrupted function call (POSIX.1-2001); see signal(7).",
-            "EINVAL" => "Invalid argument (POSIX.1-2001).",
-            "EIO" => "Input/output error (POSIX.1-2001).",
-            "EISCONN" => "Socket is connected (POSIX.1-2001).",
-            "EISDIR" => "Is a directory (POSIX.1-2001).",
-            "EISNAM" => "Is a named type file.",
-            "EKEYEXPIRED" => "Key has expired.",
-            "EKEYREJECTED" => "Key was rejected by service.",
-            "EKEYREVOKED" => "Key has been revoked.",
-            "EL2HLT" => "Level 2 halted.",
-            "EL2NSYNC" => "Level 2 not synchronized.",
-            "EL3HLT" => "Level 3 halted.",
-            "EL3RST" => "Level 3 reset.",
-            "ELIBACC" => "Cannot access a needed shared library.",
-            "ELIBBAD" => "Accessing a corrupted shared library.",
-            "ELIBMAX" => "Attempting to link in too many shared libraries.",
-            "ELIBSCN" => ".lib section in a.out corrupted",
-            "ELIBEXEC" => "Cannot exec a shared library directly.",
-            "ELNRNG" => "Link number out of range.",
-            "ELOOP" => "Too many levels of symbolic links (POSIX.1-2001).",
-            "EMEDIUMTYPE" => "Wrong medium type.",
-            "EMFILE" => "Too many open files (POSIX.1-2001). Commonly caused by exceeding the RLIMIT_NOFILE resource limit described in getrlimit(2). Can also be caused by exceeding the limit specified in /proc/sys/fs/nr_open.",
-            "EMLINK" => "Too many links (POSIX.1-2001).",
-            "EMSGSIZE" => "Message too long (POSIX.1-2001).",
-            "EMULTIHOP" => "Multihop attempted (POSIX.1-2001).",
-            "ENAMETOOLONG" => "Filename too long (POSIX.1-2001).",
-            "ENETDOWN" => "Network is down (POSIX.1-2001).",
-            "ENETRESET" => "Connection aborted by network (POSIX.1-2001).",
-            "ENETUNREACH" => "Network unreachable (POSIX.1-2001).",
-            "ENFILE" => "Too many open files in system (POSIX.1-2001). On Linux, this is probably a result of encountering the /proc/sys/fs/file-max limit (see proc(5)).",
-            "ENOANO" => "No anode.",
-            "ENOBUFS" => "No buffer space available (POSIX.1 (XSI STREAMS option)).",
-            "ENODATA" => "The named attribute does not exist, or the process has no access to this attribute; see xattr(7). In POSIX.1-2001 (XSI STREAMS option), this error was described as \"No message is available on the STREAM head read queue\".",
-            "ENODEV" => "No such device (POSIX.1-2001).",
-            "ENOENT" => "No such file or directory (POSIX.1-2001). Typically, this error results when a specified pathname does not exist, or one of the components in the directory prefix of a pathname does not exist, or the specified pathname is a dangling symbolic link.",
-            "ENOEXEC" => "Exec format error (POSIX.1-2001).",
-            "ENOKEY" => "Required key not available.",
-            "ENOLCK" => "No locks available (POSIX.1-2001).",
-            "ENOLINK" => "Link has been severed (POSIX.1-2001).",
-            "ENOMEDIUM" => "No medium found.",
-            "ENOMEM" => "Not enough space/cannot allocate memory (POSIX.1-2001).",
-            "ENOMSG" => "No message of the desired type (POSIX.1-2001).",
-            "ENONET" => "Machine is not on the network.",
-            "ENOPKG" => "Package not installed.",
-            "ENOPROTOOPT" => "Protocol not available (POSIX.1-2001).",
-            "ENOSPC" => "No space left on device (POSIX.1-2001).",
-            "ENOSR" => "No STREAM resources (POSIX.1 (XSI STREAMS option)).",
-            "ENOSTR" => "Not a STREAM (POSIX.1 (XSI STREAMS option)).",
-            "ENOSYS" => "Function not implemented (POSIX.1-2001).",
-            "ENOTBLK" => "Block device required.",
-            "ENOTCONN" => "The socket is not connected (POSIX.1-2001).",
-            "ENOTDIR" => "Not a directory (POSIX.1-2001).",
-            "ENOTEMPTY" => "Directory not empty (POSIX.1-2001).",
-            "ENOTRECOVERABLE" => "State not recoverable (POSIX.1-2008).",
-            "ENOTSOCK" => "Not a socket (POSIX.1-2001).",
-            "ENOTSUP" => "Operation not supported (POSIX.1-2001).",
-            "ENOTTY" => "Inappropriate I/O control operation (POSIX.1-2001).",
-            "ENOTUNIQ" => "Name not unique on network.",
-            "ENXIO" => "No such device or address (POSIX.1-2001).",
-            "EOPNOTSUPP" => "Operation not supported on socket (POSIX.1-2001). (ENOTSUP and EOPNOTSUPP have the same value on Linux, but according to POSIX.1 these error values should be distinct.)",
-            "EOVERFLOW" => "Value too large to be stored in data type (POSIX.1-2001).",
-            "EOWNERDEAD" => "Owner died (POSIX.1-2008).",
-            "EPERM" => "Operation not permitted (POSIX.1-2001).",
-            "EPFNOSUPPORT" => "Protocol family not supported.",
-            "EPIPE" => "Broken pipe (POSIX.1-2001).",
-            "EPROTO" => "Protocol error (POSIX.1-2001).",
-            "EPROTONOSUPPORT" => "Protocol not supported (POSIX.1-2001).",
-            "EPROTOTYPE" => "Protocol wrong type for socket (POSIX.1-2001).",
-            "ERANGE" => "Result too large (POSIX.1, C99).",
-            "EREMCHG" => "Remote address changed.",
-            "EREMOTE" => "Object is remote.",
-            "EREMOTEIO" => "Remote I/O error.",
-            "ERESTART" => "Interrupted system call should be restarted.",
-            "ERFKILL" => "Operation not possible due to RF-kill.",
-            "EROFS" => "Read-only filesystem (POSIX.1-2001).",
-            "ESHUTDOWN" => "Cannot send after transport endpoint shutdown.",
-            "ESPIPE" => "Invalid seek (POSIX.1-2001).",
-            "ESOCKTNOSUPPORT" => "Socket type not supported.",
-            "ESRCH" => "No such process (POSIX.1-2001).",
-            "ESTALE" => "Stale file handle (POSIX.1-2001). This error can occur for NFS and for other filesystems.",
-            "ESTRPIPE" => "Streams pipe error.",
-            "ETIME" => "Timer expired (POSIX.1 (XSI STREAMS option)). (POSIX.1 says \"STREAM ioctl(2) timeout\".)",
-            "ETIMEDOUT" => "Connection timed out (POSIX.1-2001).",
-            "ETOOMANYREFS" => "Too many references: cannot splice.",
-            "ETXTBSY" => "Text file busy (POSIX.1-2001).",
-            "EUCLEAN" => "Structure needs cleaning.",
-            "EUNATCH" => "Protocol driver not attached.",
-            "EUSERS" => "Too many users.",
-            "EWOULDBLOCK" => "Operation would block (may be same value as EAGAIN) (POSIX.1-2001).",
-            "EXDEV" => "Improper link (POSIX.1-2001).",
-            "EXFULL" => "Exchange full.",
-            _ => "Unknown error",
+    /// Returns the description taken from the Linux man-pages
+    ///
+    /// Errors that Linux does not have, or that mean something else on the other system,
+    /// are described after the headers of that system.
+    pub fn manpages_description(&self) -> String {
+        match (self.foreign.and_then(|row| row.2), self.entry) {
+            (Some(description), _) => description.to_owned(),
+            (None, Some(entry)) => entry.description.to_owned(),
+            (None, None) => {
+                unreachable!("the other systems describe the errors Linux does not have")
+            }
         }
-        .to_owned()
     }
 }
@@ -0,0 +1,528 @@
+// NOTE: numbers taken from the libc crate 0.2.190; descriptions are only given
+// for the names Linux does not have, or where the meaning differs from Linux
+
+use super::Foreign;
+
+/// FreeBSD
+pub(super) static FREEBSD: &[Foreign] = &[
+    ("EPERM", 1, None),
+    ("ENOENT", 2, None),
+    ("ESRCH", 3, None),
+    ("EINTR", 4, None),
+    ("EIO", 5, None),
+    ("ENXIO", 6, None),
+    ("E2BIG", 7, None),
+    ("ENOEXEC", 8, None),
+    ("EBADF", 9, None),
+    ("ECHILD", 10, None),
+    ("EDEADLK", 11, None),
+    ("ENOMEM", 12, None),
+    ("EACCES", 13, None),
+    ("EFAULT", 14, None),
+    ("ENOTBLK", 15, None),
+    ("EBUSY", 16, None),
+    ("EEXIST", 17, None),
+    ("EXDEV", 18, None),
+    ("ENODEV", 19, None),
+    ("ENOTDIR", 20, None),
+    ("EISDIR", 21, None),
+    ("EINVAL", 22, None),
+    ("ENFILE", 23, None),
+    ("EMFILE", 24, None),
+    ("ENOTTY", 25, None),
+    ("ETXTBSY", 26, None),
+    ("EFBIG", 27, None),
+    ("ENOSPC", 28, None),
+    ("ESPIPE", 29, None),
+    ("EROFS", 30, None),
+    ("EMLINK", 31, None),
+    ("EPIPE", 32, None),
+    ("EDOM", 33, None),
+    ("ERANGE", 34, None),
+    ("EAGAIN", 35, None),
+    ("EWOULDBLOCK", 35, None),
+    ("EINPROGRESS", 36, None),
+    ("EALREADY", 37, None),
+    ("ENOTSOCK", 38, None),
+    ("EDESTADDRREQ", 39, None),
+    ("EMSGSIZE", 40, None),
+    ("EPROTOTYPE", 41, None),
+    ("ENOPROTOOPT", 42, None),
+    ("EPROTONOSUPPORT", 43, None),
+    ("ESOCKTNOSUPPORT", 44, None),
+    ("EOPNOTSUPP", 45, None),
+    ("ENOTSUP", 45, None),
+    ("EPFNOSUPPORT", 46, None),
+    ("EAFNOSUPPORT", 47, None),
+    ("EADDRINUSE", 48, None),
+    ("EADDRNOTAVAIL", 49, None),
+    ("ENETDOWN", 50, None),
+    ("ENETUNREACH", 51, None),
+    ("ENETRESET", 52, None),
+    ("ECONNABORTED", 53, None),
+    ("ECONNRESET", 54, None),
+    ("ENOBUFS", 55, None),
+    ("EISCONN", 56, None),
+    ("ENOTCONN", 57, None),
+    ("ESHUTDOWN", 58, None),
+    ("ETOOMANYREFS", 59, None),
+    ("ETIMEDOUT", 60, None),
+    ("ECONNREFUSED", 61, None),
+    ("ELOOP", 62, None),
+    ("ENAMETOOLONG", 63, None),
+    ("EHOSTDOWN", 64, None),
+    ("EHOSTUNREACH", 65, None),
+    ("ENOTEMPTY", 66, None),
+    ("EPROCLIM", 67, Some("Too many processes")),
+    ("EUSERS", 68, None),
+    ("EDQUOT", 69, None),
+    ("ESTALE", 70, None),
+    ("EREMOTE", 71, None),
+    ("EBADRPC", 72, Some("RPC struct is bad")),
+    ("ERPCMISMATCH", 73, Some("RPC version wrong")),
+    ("EPROGUNAVAIL", 74, Some("RPC prog. not avail")),
+    ("EPROGMISMATCH", 75, Some("Program version wrong")),
+    ("EPROCUNAVAIL", 76, Some("Bad procedure for program")),
+    ("ENOLCK", 77, None),
+    ("ENOSYS", 78, None),
+    ("EFTYPE", 79, Some("Inappropriate file type or format")),
+    ("EAUTH", 80, Some("Authentication error")),
+    ("ENEEDAUTH", 81, Some("Need authenticator")),
+    ("EIDRM", 82, None),
+    ("ENOMSG", 83, None),
+    ("EOVERFLOW", 84, None),
+    ("ECANCELED", 85, None),
+    ("EILSEQ", 86, None),
+    ("ENOATTR", 87, Some("Attribute not found")),
+    ("EDOOFUS", 88, Some("Programming error")),
+    ("EBADMSG", 89, None),
+    ("EMULTIHOP", 90, None),
+    ("ENOLINK", 91, None),
+    ("EPROTO", 92, None),
+    ("ENOTCAPABLE", 93, Some("Capabilities insufficient")),
+    ("ECAPMODE", 94, Some("Not permitted in capability mode")),
+    ("ENOTRECOVERABLE", 95, None),
+    ("EOWNERDEAD", 96, None),
+    ("EINTEGRITY", 97, Some("Integrity check failed")),
+];
+
+/// macOS
+pub(super) static MACOS: &[Foreign] = &[
+    ("EPERM", 1, None),
+    ("ENOENT", 2, None),
+    ("ESRCH", 3, None),
+    ("EINTR", 4, None),
+    ("EIO", 5, None),
+    ("ENXIO", 6, None),
+    ("E2BIG", 7, None),
+    ("ENOEXEC", 8, None),
+    ("EBADF", 9, None),
+    ("ECHILD", 10, None),
+    ("EDEADLK", 11, None),
+    ("ENOMEM", 12, None),
+    ("EACCES", 13, None),
+    ("EFAULT", 14, None),
+    ("ENOTBLK", 15, None),
+    ("EBUSY", 16, None),
+    ("EEXIST", 17, None),
+    ("EXDEV", 18, None),
+    ("ENODEV", 19, None),
+    ("ENOTDIR", 20, None),
+    ("EISDIR", 21, None),
+    ("EINVAL", 22, None),
+    ("ENFILE", 23, None),
+    ("EMFILE", 24, None),
+    ("ENOTTY", 25, None),
+    ("ETXTBSY", 26, None),
+    ("EFBIG", 27, None),
+    ("ENOSPC", 28, None),
+    ("ESPIPE", 29, None),
+    ("EROFS", 30, None),
+    ("EMLINK", 31, None),
+    ("EPIPE", 32, None),
+    ("EDOM", 33, None),
+    ("ERANGE", 34, None),
+    ("EAGAIN", 35, None),
+    ("EWOULDBLOCK", 35, None),
+    ("EINPROGRESS", 36, None),
+    ("EALREADY", 37, None),
+    ("ENOTSOCK", 38, None),
+    ("EDESTADDRREQ", 39, None),
+    ("EMSGSIZE", 40, None),
+    ("EPROTOTYPE", 41, None),
+    ("ENOPROTOOPT", 42, None),
+    ("EPROTONOSUPPORT", 43, None),
+    ("ESOCKTNOSUPPORT", 44, None),
+    ("ENOTSUP", 45, None),
+    ("EPFNOSUPPORT", 46, None),
+    ("EAFNOSUPPORT", 47, None),
+    ("EADDRINUSE", 48, None),
+    ("EADDRNOTAVAIL", 49, None),
+    ("ENETDOWN", 50, None),
+    ("ENETUNREACH", 51, None),
+    ("ENETRESET", 52, None),
+    ("ECONNABORTED", 53, None),
+    ("ECONNRESET", 54, None),
+    ("ENOBUFS", 55, None),
+    ("EISCONN", 56, None),
+    ("ENOTCONN", 57, None),
+    ("ESHUTDOWN", 58, None),
+    ("ETOOMANYREFS", 59, None),
+    ("ETIMEDOUT", 60, None),
+    ("ECONNREFUSED", 61, None),
+    ("ELOOP", 62, None),
+    ("ENAMETOOLONG", 63, None),
+    ("EHOSTDOWN", 64, None),
+    ("EHOSTUNREACH", 65, None),
+    ("ENOTEMPTY", 66, None),
+    ("EPROCLIM", 67, Some("Too many processes")),
+    ("EUSERS", 68, None),
+    ("EDQUOT", 69, None),
+    ("ESTALE", 70, None),
+    ("EREMOTE", 71, None),
+    ("EBADRPC", 72, Some("RPC struct is bad")),
+    ("ERPCMISMATCH", 73, Some("RPC version wrong")),
+    ("EPROGUNAVAIL", 74, Some("RPC prog. not avail")),
+    ("EPROGMISMATCH", 75, Some("Program version wrong")),
+    ("EPROCUNAVAIL", 76, Some("Bad procedure for program")),
+    ("ENOLCK", 77, None),
+    ("ENOSYS", 78, None),
+    ("EFTYPE", 79, Some("Inappropriate file type or format")),
+    ("EAUTH", 80, Some("Authentication error")),
+    ("ENEEDAUTH", 81, Some("Need authenticator")),
+    ("EPWROFF", 82, Some("Device power is off")),
+    ("EDEVERR", 83, Some("Device error")),
+    ("EOVERFLOW", 84, None),
+    ("EBADEXEC", 85, Some("Bad executable (or shared library)")),
+    ("EBADARCH", 86, Some("Bad CPU type in executable")),
+    ("ESHLIBVERS", 87, Some("Shared library version mismatch")),
+    ("EBADMACHO", 88, Some("Malformed Mach-o file")),
+    ("ECANCELED", 89, None),
+    ("EIDRM", 90, None),
+    ("ENOMSG", 91, None),
+    ("EILSEQ", 92, None),
+    ("ENOATTR", 93, Some("Attribute not found")),
+    ("EBADMSG", 94, None),
+    ("EMULTIHOP", 95, None),
+    ("ENODATA", 96, None),
+    ("ENOLINK", 97, None),
+    ("ENOSR", 98, None),
+    ("ENOSTR", 99, None),
+    ("EPROTO", 100, None),
+    ("ETIME", 101, None),
+    ("EOPNOTSUPP", 102, None),
+    ("ENOPOLICY", 103, Some("Policy not found")),
+    ("ENOTRECOVERABLE", 104, None),
+    ("EOWNERDEAD", 105, None),
+    ("EQFULL", 106, Some("Interface output queue is full")),
+    ("ENOTCAPABLE", 107, Some("Capabilities insufficient")),
+];
+
+/// OpenBSD
+pub(super) static OPENBSD: &[Foreign] = &[
+    ("EPERM", 1, None),
+    ("ENOENT", 2, None),
+    ("ESRCH", 3, None),
+    ("EINTR", 4, None),
+    ("EIO", 5, None),
+    ("ENXIO", 6, None),
+    ("E2BIG", 7, None),
+    ("ENOEXEC", 8, None),
+    ("EBADF", 9, None),
+    ("ECHILD", 10, None),
+    ("EDEADLK", 11, None),
+    ("ENOMEM", 12, None),
+    ("EACCES", 13, None),
+    ("EFAULT", 14, None),
+    ("ENOTBLK", 15, None),
+    ("EBUSY", 16, None),
+    ("EEXIST", 17, None),
+    ("EXDEV", 18, None),
+    ("ENODEV", 19, None),
+    ("ENOTDIR", 20, None),
+    ("EISDIR", 21, None),
+    ("EINVAL", 22, None),
+    ("ENFILE", 23, None),
+    ("EMFILE", 24, None),
+    ("ENOTTY", 25, None),
+    ("ETXTBSY", 26, None),
+    ("EFBIG", 27, None),
+    ("ENOSPC", 28, None),
+    ("ESPIPE", 29, None),
+    ("EROFS", 30, None),
+    ("EMLINK", 31, None),
+    ("EPIPE", 32, None),
+    ("EDOM", 33, None),
+    ("ERANGE", 34, None),
+    ("EAGAIN", 35, None),
+    ("EWOULDBLOCK", 35, None),
+    ("EINPROGRESS", 36, None),
+    ("EALREADY", 37, None),
+    ("ENOTSOCK", 38, None),
+    ("EDESTADDRREQ", 39, None),
+    ("EMSGSIZE", 40, None),
+    ("EPROTOTYPE", 41, None),
+    ("ENOPROTOOPT", 42, None),
+    ("EPROTONOSUPPORT", 43, None),
+    ("ESOCKTNOSUPPORT", 44, None),
+    ("EOPNOTSUPP", 45, None),
+    ("EPFNOSUPPORT", 46, None),
+    ("EAFNOSUPPORT", 47, None),
+    ("EADDRINUSE", 48, None),
+    ("EADDRNOTAVAIL", 49, None),
+    ("ENETDOWN", 50, None),
+    ("ENETUNREACH", 51, None),
+    ("ENETRESET", 52, None),
+    ("ECONNABORTED", 53, None),
+    ("ECONNRESET", 54, None),
+    ("ENOBUFS", 55, None),
+    ("EISCONN", 56, None),
+    ("ENOTCONN", 57, None),
+    ("ESHUTDOWN", 58, None),
+    ("ETOOMANYREFS", 59, None),
+    ("ETIMEDOUT", 60, None),
+    ("ECONNREFUSED", 61, None),
+    ("ELOOP", 62, None),
+    ("ENAMETOOLONG", 63, None),
+    ("EHOSTDOWN", 64, None),
+    ("EHOSTUNREACH", 65, None),
+    ("ENOTEMPTY", 66, None),
+    ("EPROCLIM", 67, Some("Too many processes")),
+    ("EUSERS", 68, None),
+    ("EDQUOT", 69, None),
+    ("ESTALE", 70, None),
+    ("EREMOTE", 71, None),
+    ("EBADRPC", 72, Some("RPC struct is bad")),
+    ("ERPCMISMATCH", 73, Some("RPC version wrong")),
+    ("EPROGUNAVAIL", 74, Some("RPC prog. not avail")),
+    ("EPROGMISMATCH", 75, Some("Program version wrong")),
+    ("EPROCUNAVAIL", 76, Some("Bad procedure for program")),
+    ("ENOLCK", 77, None),
+    ("ENOSYS", 78, None),
+    ("EFTYPE", 79, Some("Inappropriate file type or format")),
+    ("EAUTH", 80, Some("Authentication error")),
+    ("ENEEDAUTH", 81, Some("Need authenticator")),
+    ("EIPSEC", 82, Some("IPsec processing failure")),
+    ("ENOATTR", 83, Some("Attribute not found")),
+    ("EILSEQ", 84, None),
+    ("ENOMEDIUM", 85, None),
+    ("EMEDIUMTYPE", 86, None),
+    ("EOVERFLOW", 87, None),
+    ("ECANCELED", 88, None),
+    ("EIDRM", 89, None),
+    ("ENOMSG", 90, None),
+    ("ENOTSUP", 91, None),
+    ("EBADMSG", 92, None),
+    ("ENOTRECOVERABLE", 93, None),
+    ("EOWNERDEAD", 94, None),
+    ("EPROTO", 95, None),
+];
+
+/// illumos
+pub(super) static ILLUMOS: &[Foreign] = &[
+    ("EPERM", 1, None),
+    ("ENOENT", 2, None),
+    ("ESRCH", 3, None),
+    ("EINTR", 4, None),
+    ("EIO", 5, None),
+    ("ENXIO", 6, None),
+    ("E2BIG", 7, None),
+    ("ENOEXEC", 8, None),
+    ("EBADF", 9, None),
+    ("ECHILD", 10, None),
+    ("EAGAIN", 11, None),
+    ("EWOULDBLOCK", 11, None),
+    ("ENOMEM", 12, None),
+    ("EACCES", 13, None),
+    ("EFAULT", 14, None),
+    ("ENOTBLK", 15, None),
+    ("EBUSY", 16, None),
+    ("EEXIST", 17, None),
+    ("EXDEV", 18, None),
+    ("ENODEV", 19, None),
+    ("ENOTDIR", 20, None),
+    ("EISDIR", 21, None),
+    ("EINVAL", 22, None),
+    ("ENFILE", 23, None),
+    ("EMFILE", 24, None),
+    ("ENOTTY", 25, None),
+    ("ETXTBSY", 26, None),
+    ("EFBIG", 27, None),
+    ("ENOSPC", 28, None),
+    ("ESPIPE", 29, None),
+    ("EROFS", 30, None),
+    ("EMLINK", 31, None),
+    ("EPIPE", 32, None),
+    ("EDOM", 33, None),
+    ("ERANGE", 34, None),
+    ("ENOMSG", 35, None),
+    ("EIDRM", 36, None),
+    ("ECHRNG", 37, None),
+    ("EL2NSYNC", 38, None),
+    ("EL3HLT", 39, None),
+    ("EL3RST", 40, None),
+    ("ELNRNG", 41, None),
+    ("EUNATCH", 42, None),
+    ("ENOCSI", 43, Some("No CSI structure available")),
+    ("EL2HLT", 44, None),
+    ("EDEADLK", 45, None),
+    ("ENOLCK", 46, None),
+    ("ECANCELED", 47, None),
+    ("ENOTSUP", 48, None),
+    ("EDQUOT", 49, None),
+    ("EBADE", 50, None),
+    ("EBADR", 51, None),
+    ("EXFULL", 52, None),
+    ("ENOANO", 53, None),
+    ("EBADRQC", 54, None),
+    ("EBADSLT", 55, None),
+    ("EDEADLOCK", 56, None),
+    ("EBFONT", 57, Some("Bad font file format")),
+    ("EOWNERDEAD", 58, None),
+    ("ENOTRECOVERABLE", 59, None),
+    ("ENOSTR", 60, None),
+    ("ENODATA", 61, None),
+    ("ETIME", 62, None),
+    ("ENOSR", 63, None),
+    ("ENONET", 64, None),
+    ("ENOPKG", 65, None),
+    ("EREMOTE", 66, None),
+    ("ENOLINK", 67, None),
+    ("EADV", 68, Some("Advertise error")),
+    ("ESRMNT", 69, Some("Srmount error")),
+    ("ECOMM", 70, None),
+    ("EPROTO", 71, None),
+    ("ELOCKUNMAPPED", 72, Some("Locked lock was unmapped")),
+    ("ENOTACTIVE", 73, Some("Facility is not active")),
+    ("EMULTIHOP", 74, None),
+    ("EADI", 75, Some("Application Data Integrity error")),
+    ("EBADMSG", 77, None),
+    ("ENAMETOOLONG", 78, None),
+    ("EOVERFLOW", 79, None),
+    ("ENOTUNIQ", 80, None),
+    ("EBADFD", 81, None),
+    ("EREMCHG", 82, None),
+    ("ELIBACC", 83, None),
+    ("ELIBBAD", 84, None),
+    ("ELIBSCN", 85, None),
+    ("ELIBMAX", 86, None),
+    ("ELIBEXEC", 87, None),
+    ("EILSEQ", 88, None),
+    ("ENOSYS", 89, None),
+    ("ELOOP", 90, None),
+    ("ERESTART", 91, None),
+    ("ESTRPIPE", 92, None),
+    ("ENOTEMPTY", 93, None),
+    ("EUSERS", 94, None),
+    ("ENOTSOCK", 95, None),
+    ("EDESTADDRREQ", 96, None),
+    ("EMSGSIZE", 97, None),
+    ("EPROTOTYPE", 98, None),
+    ("ENOPROTOOPT", 99, None),
+    ("EPROTONOSUPPORT", 120, None),
+    ("ESOCKTNOSUPPORT", 121, None),
+    ("EOPNOTSUPP", 122, None),
+    ("EPFNOSUPPORT", 123, None),
+    ("EAFNOSUPPORT", 124, None),
+    ("EADDRINUSE", 125, None),
+    ("EADDRNOTAVAIL", 126, None),
+    ("ENETDOWN", 127, None),
+    ("ENETUNREACH", 128, None),
+    ("ENETRESET", 129, None),
+    ("ECONNABORTED", 130, None),
+    ("ECONNRESET", 131, None),
+    ("ENOBUFS", 132, None),
+    ("EISCONN", 133, None),
+    ("ENOTCONN", 134, None),
+    ("ESHUTDOWN", 143, None),
+    ("ETOOMANYREFS", 144, None),
+    ("ETIMEDOUT", 145, None),
+    ("ECONNREFUSED", 146, None),
+    ("EHOSTDOWN", 147, None),
+    ("EHOSTUNREACH", 148, None),
+    ("EALREADY", 149, None),
+    ("EINPROGRESS", 150, None),
+    ("ESTALE", 151, None),
+];
+
+/// WASI
+pub(super) static WASI: &[Foreign] = &[
+    ("E2BIG", 1, None),
+    ("EACCES", 2, None),
+    ("EADDRINUSE", 3, None),
+    ("EADDRNOTAVAIL", 4, None),
+    ("EAFNOSUPPORT", 5, None),
+    ("EAGAIN", 6, None),
+    ("EWOULDBLOCK", 6, None),
+    ("EALREADY", 7, None),
+    ("EBADF", 8, None),
+    ("EBADMSG", 9, None),
+    ("EBUSY", 10, None),
+    ("ECANCELED", 11, None),
+    ("ECHILD", 12, None),
+    ("ECONNABORTED", 13, None),
+    ("ECONNREFUSED", 14, None),
+    ("ECONNRESET", 15, None),
+    ("EDEADLK", 16, None),
+    ("EDESTADDRREQ", 17, None),
+    ("EDOM", 18, None),
+    ("EDQUOT", 19, None),
+    ("EEXIST", 20, None),
+    ("EFAULT", 21, None),
+    ("EFBIG", 22, None),
+    ("EHOSTUNREACH", 23, None),
+    ("EIDRM", 24, None),
+    ("EILSEQ", 25, None),
+    ("EINPROGRESS", 26, None),
+    ("EINTR", 27, None),
+    ("EINVAL", 28, None),
+    ("EIO", 29, None),
+    ("EISCONN", 30, None),
+    ("EISDIR", 31, None),
+    ("ELOOP", 32, None),
+    ("EMFILE", 33, None),
+    ("EMLINK", 34, None),
+    ("EMSGSIZE", 35, None),
+    ("EMULTIHOP", 36, None),
+    ("ENAMETOOLONG", 37, None),
+    ("ENETDOWN", 38, None),
+    ("ENETRESET", 39, None),
+    ("ENETUNREACH", 40, None),
+    ("ENFILE", 41, None),
+    ("ENOBUFS", 42, None),
+    ("ENODEV", 43, None),
+    ("ENOENT", 44, None),
+    ("ENOEXEC", 45, None),
+    ("ENOLCK", 46, None),
+    ("ENOLINK", 47, None),
+    ("ENOMEM", 48, None),
+    ("ENOMSG", 49, None),
+    ("ENOPROTOOPT", 50, None),
+    ("ENOSPC", 51, None),
+    ("ENOSYS", 52, None),
+    ("ENOTCONN", 53, None),
+    ("ENOTDIR", 54, None),
+    ("ENOTEMPTY", 55, None),
+    ("ENOTRECOVERABLE", 56, None),
+    ("ENOTSOCK", 57, None),
+    ("ENOTSUP", 58, None),
+    ("EOPNOTSUPP", 58, None),
+    ("ENOTTY", 59, None),
+    ("ENXIO", 60, None),
+    ("EOVERFLOW", 61, None),
+    ("EOWNERDEAD", 62, None),
+    ("EPERM", 63, None),
+    ("EPIPE", 64, None),
+    ("EPROTO", 65, None),
+    ("EPROTONOSUPPORT", 66, None),
+    ("EPROTOTYPE", 67, None),
+    ("ERANGE", 68, None),
+    ("EROFS", 69, None),
+    ("ESPIPE", 70, None),
+    ("ESRCH", 71, None),
+    ("ESTALE", 72, None),
+    ("ETIMEDOUT", 73, None),
+    ("ETXTBSY", 74, None),
+    ("EXDEV", 75, None),
+    ("ENOTCAPABLE", 76, Some("Capabilities insufficient")),
+];
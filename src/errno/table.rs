@@ -0,0 +1,1278 @@
+// NOTE: generated by `cargo xtask tables` from Linux man-pages 6.01
+// `number`, `alpha`, `mips`, `powerpc`, `sparc` and `alias_of` are kept when regenerating,
+// the other fields come from the man page
+
+use super::Entry;
+
+pub(super) static TABLE: &[Entry] = &[
+    Entry {
+        name: "E2BIG",
+        number: libc::E2BIG,
+        alpha: 7,
+        mips: 7,
+        powerpc: 7,
+        sparc: 7,
+        alias_of: None,
+        description: "Argument list too long (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EACCES",
+        number: libc::EACCES,
+        alpha: 13,
+        mips: 13,
+        powerpc: 13,
+        sparc: 13,
+        alias_of: None,
+        description: "Permission denied (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EADDRINUSE",
+        number: libc::EADDRINUSE,
+        alpha: 48,
+        mips: 125,
+        powerpc: 98,
+        sparc: 48,
+        alias_of: None,
+        description: "Address already in use (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EADDRNOTAVAIL",
+        number: libc::EADDRNOTAVAIL,
+        alpha: 49,
+        mips: 126,
+        powerpc: 99,
+        sparc: 49,
+        alias_of: None,
+        description: "Address not available (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EAFNOSUPPORT",
+        number: libc::EAFNOSUPPORT,
+        alpha: 47,
+        mips: 124,
+        powerpc: 97,
+        sparc: 47,
+        alias_of: None,
+        description: "Address family not supported (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EAGAIN",
+        number: libc::EAGAIN,
+        alpha: 35,
+        mips: 11,
+        powerpc: 11,
+        sparc: 11,
+        alias_of: None,
+        description: "Resource temporarily unavailable (may be the same value as EWOULDBLOCK) (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EALREADY",
+        number: libc::EALREADY,
+        alpha: 37,
+        mips: 149,
+        powerpc: 114,
+        sparc: 37,
+        alias_of: None,
+        description: "Connection already in progress (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EBADE",
+        number: libc::EBADE,
+        alpha: 97,
+        mips: 50,
+        powerpc: 52,
+        sparc: 102,
+        alias_of: None,
+        description: "Invalid exchange.",
+    },
+    Entry {
+        name: "EBADF",
+        number: libc::EBADF,
+        alpha: 9,
+        mips: 9,
+        powerpc: 9,
+        sparc: 9,
+        alias_of: None,
+        description: "Bad file descriptor (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EBADFD",
+        number: libc::EBADFD,
+        alpha: 114,
+        mips: 81,
+        powerpc: 77,
+        sparc: 93,
+        alias_of: None,
+        description: "File descriptor in bad state.",
+    },
+    Entry {
+        name: "EBADMSG",
+        number: libc::EBADMSG,
+        alpha: 84,
+        mips: 77,
+        powerpc: 74,
+        sparc: 76,
+        alias_of: None,
+        description: "Bad message (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EBADR",
+        number: libc::EBADR,
+        alpha: 98,
+        mips: 51,
+        powerpc: 53,
+        sparc: 103,
+        alias_of: None,
+        description: "Invalid request descriptor.",
+    },
+    Entry {
+        name: "EBADRQC",
+        number: libc::EBADRQC,
+        alpha: 101,
+        mips: 54,
+        powerpc: 56,
+        sparc: 106,
+        alias_of: None,
+        description: "Invalid request code.",
+    },
+    Entry {
+        name: "EBADSLT",
+        number: libc::EBADSLT,
+        alpha: 102,
+        mips: 55,
+        powerpc: 57,
+        sparc: 107,
+        alias_of: None,
+        description: "Invalid slot.",
+    },
+    Entry {
+        name: "EBUSY",
+        number: libc::EBUSY,
+        alpha: 16,
+        mips: 16,
+        powerpc: 16,
+        sparc: 16,
+        alias_of: None,
+        description: "Device or resource busy (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ECANCELED",
+        number: libc::ECANCELED,
+        alpha: 131,
+        mips: 158,
+        powerpc: 125,
+        sparc: 127,
+        alias_of: None,
+        description: "Operation canceled (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ECHILD",
+        number: libc::ECHILD,
+        alpha: 10,
+        mips: 10,
+        powerpc: 10,
+        sparc: 10,
+        alias_of: None,
+        description: "No child processes (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ECHRNG",
+        number: libc::ECHRNG,
+        alpha: 88,
+        mips: 37,
+        powerpc: 44,
+        sparc: 94,
+        alias_of: None,
+        description: "Channel number out of range.",
+    },
+    Entry {
+        name: "ECOMM",
+        number: libc::ECOMM,
+        alpha: 109,
+        mips: 70,
+        powerpc: 70,
+        sparc: 85,
+        alias_of: None,
+        description: "Communication error on send.",
+    },
+    Entry {
+        name: "ECONNABORTED",
+        number: libc::ECONNABORTED,
+        alpha: 53,
+        mips: 130,
+        powerpc: 103,
+        sparc: 53,
+        alias_of: None,
+        description: "Connection aborted (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ECONNREFUSED",
+        number: libc::ECONNREFUSED,
+        alpha: 61,
+        mips: 146,
+        powerpc: 111,
+        sparc: 61,
+        alias_of: None,
+        description: "Connection refused (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ECONNRESET",
+        number: libc::ECONNRESET,
+        alpha: 54,
+        mips: 131,
+        powerpc: 104,
+        sparc: 54,
+        alias_of: None,
+        description: "Connection reset (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EDEADLK",
+        number: libc::EDEADLK,
+        alpha: 11,
+        mips: 45,
+        powerpc: 35,
+        sparc: 78,
+        alias_of: None,
+        description: "Resource deadlock avoided (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EDEADLOCK",
+        number: libc::EDEADLOCK,
+        alpha: 11,
+        mips: 56,
+        powerpc: 58,
+        sparc: 108,
+        alias_of: Some("EDEADLK"),
+        description: "On most architectures, a synonym for EDEADLK. On some architectures (e.g., Linux MIPS, PowerPC, SPARC), it is a separate error code \"File locking deadlock error\".",
+    },
+    Entry {
+        name: "EDESTADDRREQ",
+        number: libc::EDESTADDRREQ,
+        alpha: 39,
+        mips: 96,
+        powerpc: 89,
+        sparc: 39,
+        alias_of: None,
+        description: "Destination address required (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EDOM",
+        number: libc::EDOM,
+        alpha: 33,
+        mips: 33,
+        powerpc: 33,
+        sparc: 33,
+        alias_of: None,
+        description: "Mathematics argument out of domain of function (POSIX.1, C99).",
+    },
+    Entry {
+        name: "EDQUOT",
+        number: libc::EDQUOT,
+        alpha: 69,
+        mips: 1133,
+        powerpc: 122,
+        sparc: 69,
+        alias_of: None,
+        description: "Disk quota exceeded (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EEXIST",
+        number: libc::EEXIST,
+        alpha: 17,
+        mips: 17,
+        powerpc: 17,
+        sparc: 17,
+        alias_of: None,
+        description: "File exists (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EFAULT",
+        number: libc::EFAULT,
+        alpha: 14,
+        mips: 14,
+        powerpc: 14,
+        sparc: 14,
+        alias_of: None,
+        description: "Bad address (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EFBIG",
+        number: libc::EFBIG,
+        alpha: 27,
+        mips: 27,
+        powerpc: 27,
+        sparc: 27,
+        alias_of: None,
+        description: "File too large (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EHOSTDOWN",
+        number: libc::EHOSTDOWN,
+        alpha: 64,
+        mips: 147,
+        powerpc: 112,
+        sparc: 64,
+        alias_of: None,
+        description: "Host is down.",
+    },
+    Entry {
+        name: "EHOSTUNREACH",
+        number: libc::EHOSTUNREACH,
+        alpha: 65,
+        mips: 148,
+        powerpc: 113,
+        sparc: 65,
+        alias_of: None,
+        description: "Host is unreachable (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EHWPOISON",
+        number: libc::EHWPOISON,
+        alpha: 139,
+        mips: 168,
+        powerpc: 133,
+        sparc: 135,
+        alias_of: None,
+        description: "Memory page has hardware error.",
+    },
+    Entry {
+        name: "EIDRM",
+        number: libc::EIDRM,
+        alpha: 81,
+        mips: 36,
+        powerpc: 43,
+        sparc: 77,
+        alias_of: None,
+        description: "Identifier removed (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EILSEQ",
+        number: libc::EILSEQ,
+        alpha: 116,
+        mips: 88,
+        powerpc: 84,
+        sparc: 122,
+        alias_of: None,
+        description: "Invalid or incomplete multibyte or wide character (POSIX.1, C99). The text shown here is the glibc error description; in POSIX.1, this error is described as \"Illegal byte sequence\".",
+    },
+    Entry {
+        name: "EINPROGRESS",
+        number: libc::EINPROGRESS,
+        alpha: 36,
+        mips: 150,
+        powerpc: 115,
+        sparc: 36,
+        alias_of: None,
+        description: "Operation in progress (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EINTR",
+        number: libc::EINTR,
+        alpha: 4,
+        mips: 4,
+        powerpc: 4,
+        sparc: 4,
+        alias_of: None,
+        description: "Interrupted function call (POSIX.1-2001); see signal(7).",
+    },
+    Entry {
+        name: "EINVAL",
+        number: libc::EINVAL,
+        alpha: 22,
+        mips: 22,
+        powerpc: 22,
+        sparc: 22,
+        alias_of: None,
+        description: "Invalid argument (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EIO",
+        number: libc::EIO,
+        alpha: 5,
+        mips: 5,
+        powerpc: 5,
+        sparc: 5,
+        alias_of: None,
+        description: "Input/output error (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EISCONN",
+        number: libc::EISCONN,
+        alpha: 56,
+        mips: 133,
+        powerpc: 106,
+        sparc: 56,
+        alias_of: None,
+        description: "Socket is connected (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EISDIR",
+        number: libc::EISDIR,
+        alpha: 21,
+        mips: 21,
+        powerpc: 21,
+        sparc: 21,
+        alias_of: None,
+        description: "Is a directory (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EISNAM",
+        number: libc::EISNAM,
+        alpha: 120,
+        mips: 139,
+        powerpc: 120,
+        sparc: 120,
+        alias_of: None,
+        description: "Is a named type file.",
+    },
+    Entry {
+        name: "EKEYEXPIRED",
+        number: libc::EKEYEXPIRED,
+        alpha: 133,
+        mips: 162,
+        powerpc: 127,
+        sparc: 129,
+        alias_of: None,
+        description: "Key has expired.",
+    },
+    Entry {
+        name: "EKEYREJECTED",
+        number: libc::EKEYREJECTED,
+        alpha: 135,
+        mips: 164,
+        powerpc: 129,
+        sparc: 131,
+        alias_of: None,
+        description: "Key was rejected by service.",
+    },
+    Entry {
+        name: "EKEYREVOKED",
+        number: libc::EKEYREVOKED,
+        alpha: 134,
+        mips: 163,
+        powerpc: 128,
+        sparc: 130,
+        alias_of: None,
+        description: "Key has been revoked.",
+    },
+    Entry {
+        name: "EL2HLT",
+        number: libc::EL2HLT,
+        alpha: 96,
+        mips: 44,
+        powerpc: 51,
+        sparc: 101,
+        alias_of: None,
+        description: "Level 2 halted.",
+    },
+    Entry {
+        name: "EL2NSYNC",
+        number: libc::EL2NSYNC,
+        alpha: 89,
+        mips: 38,
+        powerpc: 45,
+        sparc: 95,
+        alias_of: None,
+        description: "Level 2 not synchronized.",
+    },
+    Entry {
+        name: "EL3HLT",
+        number: libc::EL3HLT,
+        alpha: 90,
+        mips: 39,
+        powerpc: 46,
+        sparc: 96,
+        alias_of: None,
+        description: "Level 3 halted.",
+    },
+    Entry {
+        name: "EL3RST",
+        number: libc::EL3RST,
+        alpha: 91,
+        mips: 40,
+        powerpc: 47,
+        sparc: 97,
+        alias_of: None,
+        description: "Level 3 reset.",
+    },
+    Entry {
+        name: "ELIBACC",
+        number: libc::ELIBACC,
+        alpha: 122,
+        mips: 83,
+        powerpc: 79,
+        sparc: 114,
+        alias_of: None,
+        description: "Cannot access a needed shared library.",
+    },
+    Entry {
+        name: "ELIBBAD",
+        number: libc::ELIBBAD,
+        alpha: 123,
+        mips: 84,
+        powerpc: 80,
+        sparc: 112,
+        alias_of: None,
+        description: "Accessing a corrupted shared library.",
+    },
+    Entry {
+        name: "ELIBMAX",
+        number: libc::ELIBMAX,
+        alpha: 125,
+        mips: 86,
+        powerpc: 82,
+        sparc: 123,
+        alias_of: None,
+        description: "Attempting to link in too many shared libraries.",
+    },
+    Entry {
+        name: "ELIBSCN",
+        number: libc::ELIBSCN,
+        alpha: 124,
+        mips: 85,
+        powerpc: 81,
+        sparc: 124,
+        alias_of: None,
+        description: ".lib section in a.out corrupted",
+    },
+    Entry {
+        name: "ELIBEXEC",
+        number: libc::ELIBEXEC,
+        alpha: 126,
+        mips: 87,
+        powerpc: 83,
+        sparc: 110,
+        alias_of: None,
+        description: "Cannot exec a shared library directly.",
+    },
+    Entry {
+        name: "ELNRNG",
+        number: libc::ELNRNG,
+        alpha: 93,
+        mips: 41,
+        powerpc: 48,
+        sparc: 98,
+        alias_of: None,
+        description: "Link number out of range.",
+    },
+    Entry {
+        name: "ELOOP",
+        number: libc::ELOOP,
+        alpha: 62,
+        mips: 90,
+        powerpc: 40,
+        sparc: 62,
+        alias_of: None,
+        description: "Too many levels of symbolic links (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EMEDIUMTYPE",
+        number: libc::EMEDIUMTYPE,
+        alpha: 130,
+        mips: 160,
+        powerpc: 124,
+        sparc: 126,
+        alias_of: None,
+        description: "Wrong medium type.",
+    },
+    Entry {
+        name: "EMFILE",
+        number: libc::EMFILE,
+        alpha: 24,
+        mips: 24,
+        powerpc: 24,
+        sparc: 24,
+        alias_of: None,
+        description: "Too many open files (POSIX.1-2001). Commonly caused by exceeding the RLIMIT_NOFILE resource limit described in getrlimit(2). Can also be caused by exceeding the limit specified in /proc/sys/fs/nr_open.",
+    },
+    Entry {
+        name: "EMLINK",
+        number: libc::EMLINK,
+        alpha: 31,
+        mips: 31,
+        powerpc: 31,
+        sparc: 31,
+        alias_of: None,
+        description: "Too many links (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EMSGSIZE",
+        number: libc::EMSGSIZE,
+        alpha: 40,
+        mips: 97,
+        powerpc: 90,
+        sparc: 40,
+        alias_of: None,
+        description: "Message too long (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EMULTIHOP",
+        number: libc::EMULTIHOP,
+        alpha: 110,
+        mips: 74,
+        powerpc: 72,
+        sparc: 87,
+        alias_of: None,
+        description: "Multihop attempted (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENAMETOOLONG",
+        number: libc::ENAMETOOLONG,
+        alpha: 63,
+        mips: 78,
+        powerpc: 36,
+        sparc: 63,
+        alias_of: None,
+        description: "Filename too long (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENETDOWN",
+        number: libc::ENETDOWN,
+        alpha: 50,
+        mips: 127,
+        powerpc: 100,
+        sparc: 50,
+        alias_of: None,
+        description: "Network is down (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENETRESET",
+        number: libc::ENETRESET,
+        alpha: 52,
+        mips: 129,
+        powerpc: 102,
+        sparc: 52,
+        alias_of: None,
+        description: "Connection aborted by network (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENETUNREACH",
+        number: libc::ENETUNREACH,
+        alpha: 51,
+        mips: 128,
+        powerpc: 101,
+        sparc: 51,
+        alias_of: None,
+        description: "Network unreachable (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENFILE",
+        number: libc::ENFILE,
+        alpha: 23,
+        mips: 23,
+        powerpc: 23,
+        sparc: 23,
+        alias_of: None,
+        description: "Too many open files in system (POSIX.1-2001). On Linux, this is probably a result of encountering the /proc/sys/fs/file-max limit (see proc(5)).",
+    },
+    Entry {
+        name: "ENOANO",
+        number: libc::ENOANO,
+        alpha: 100,
+        mips: 53,
+        powerpc: 55,
+        sparc: 105,
+        alias_of: None,
+        description: "No anode.",
+    },
+    Entry {
+        name: "ENOBUFS",
+        number: libc::ENOBUFS,
+        alpha: 55,
+        mips: 132,
+        powerpc: 105,
+        sparc: 55,
+        alias_of: None,
+        description: "No buffer space available (POSIX.1 (XSI STREAMS option)).",
+    },
+    Entry {
+        name: "ENODATA",
+        number: libc::ENODATA,
+        alpha: 86,
+        mips: 61,
+        powerpc: 61,
+        sparc: 111,
+        alias_of: None,
+        description: "The named attribute does not exist, or the process has no access to this attribute; see xattr(7). In POSIX.1-2001 (XSI STREAMS option), this error was described as \"No message is available on the STREAM head read queue\".",
+    },
+    Entry {
+        name: "ENODEV",
+        number: libc::ENODEV,
+        alpha: 19,
+        mips: 19,
+        powerpc: 19,
+        sparc: 19,
+        alias_of: None,
+        description: "No such device (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOENT",
+        number: libc::ENOENT,
+        alpha: 2,
+        mips: 2,
+        powerpc: 2,
+        sparc: 2,
+        alias_of: None,
+        description: "No such file or directory (POSIX.1-2001). Typically, this error results when a specified pathname does not exist, or one of the components in the directory prefix of a pathname does not exist, or the specified pathname is a dangling symbolic link.",
+    },
+    Entry {
+        name: "ENOEXEC",
+        number: libc::ENOEXEC,
+        alpha: 8,
+        mips: 8,
+        powerpc: 8,
+        sparc: 8,
+        alias_of: None,
+        description: "Exec format error (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOKEY",
+        number: libc::ENOKEY,
+        alpha: 132,
+        mips: 161,
+        powerpc: 126,
+        sparc: 128,
+        alias_of: None,
+        description: "Required key not available.",
+    },
+    Entry {
+        name: "ENOLCK",
+        number: libc::ENOLCK,
+        alpha: 77,
+        mips: 46,
+        powerpc: 37,
+        sparc: 79,
+        alias_of: None,
+        description: "No locks available (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOLINK",
+        number: libc::ENOLINK,
+        alpha: 106,
+        mips: 67,
+        powerpc: 67,
+        sparc: 82,
+        alias_of: None,
+        description: "Link has been severed (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOMEDIUM",
+        number: libc::ENOMEDIUM,
+        alpha: 129,
+        mips: 159,
+        powerpc: 123,
+        sparc: 125,
+        alias_of: None,
+        description: "No medium found.",
+    },
+    Entry {
+        name: "ENOMEM",
+        number: libc::ENOMEM,
+        alpha: 12,
+        mips: 12,
+        powerpc: 12,
+        sparc: 12,
+        alias_of: None,
+        description: "Not enough space/cannot allocate memory (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOMSG",
+        number: libc::ENOMSG,
+        alpha: 80,
+        mips: 35,
+        powerpc: 42,
+        sparc: 75,
+        alias_of: None,
+        description: "No message of the desired type (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENONET",
+        number: libc::ENONET,
+        alpha: 105,
+        mips: 64,
+        powerpc: 64,
+        sparc: 80,
+        alias_of: None,
+        description: "Machine is not on the network.",
+    },
+    Entry {
+        name: "ENOPKG",
+        number: libc::ENOPKG,
+        alpha: 92,
+        mips: 65,
+        powerpc: 65,
+        sparc: 113,
+        alias_of: None,
+        description: "Package not installed.",
+    },
+    Entry {
+        name: "ENOPROTOOPT",
+        number: libc::ENOPROTOOPT,
+        alpha: 42,
+        mips: 99,
+        powerpc: 92,
+        sparc: 42,
+        alias_of: None,
+        description: "Protocol not available (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOSPC",
+        number: libc::ENOSPC,
+        alpha: 28,
+        mips: 28,
+        powerpc: 28,
+        sparc: 28,
+        alias_of: None,
+        description: "No space left on device (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOSR",
+        number: libc::ENOSR,
+        alpha: 82,
+        mips: 63,
+        powerpc: 63,
+        sparc: 74,
+        alias_of: None,
+        description: "No STREAM resources (POSIX.1 (XSI STREAMS option)).",
+    },
+    Entry {
+        name: "ENOSTR",
+        number: libc::ENOSTR,
+        alpha: 87,
+        mips: 60,
+        powerpc: 60,
+        sparc: 72,
+        alias_of: None,
+        description: "Not a STREAM (POSIX.1 (XSI STREAMS option)).",
+    },
+    Entry {
+        name: "ENOSYS",
+        number: libc::ENOSYS,
+        alpha: 78,
+        mips: 89,
+        powerpc: 38,
+        sparc: 90,
+        alias_of: None,
+        description: "Function not implemented (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTBLK",
+        number: libc::ENOTBLK,
+        alpha: 15,
+        mips: 15,
+        powerpc: 15,
+        sparc: 15,
+        alias_of: None,
+        description: "Block device required.",
+    },
+    Entry {
+        name: "ENOTCONN",
+        number: libc::ENOTCONN,
+        alpha: 57,
+        mips: 134,
+        powerpc: 107,
+        sparc: 57,
+        alias_of: None,
+        description: "The socket is not connected (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTDIR",
+        number: libc::ENOTDIR,
+        alpha: 20,
+        mips: 20,
+        powerpc: 20,
+        sparc: 20,
+        alias_of: None,
+        description: "Not a directory (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTEMPTY",
+        number: libc::ENOTEMPTY,
+        alpha: 66,
+        mips: 93,
+        powerpc: 39,
+        sparc: 66,
+        alias_of: None,
+        description: "Directory not empty (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTRECOVERABLE",
+        number: libc::ENOTRECOVERABLE,
+        alpha: 137,
+        mips: 166,
+        powerpc: 131,
+        sparc: 133,
+        alias_of: None,
+        description: "State not recoverable (POSIX.1-2008).",
+    },
+    Entry {
+        name: "ENOTSOCK",
+        number: libc::ENOTSOCK,
+        alpha: 38,
+        mips: 95,
+        powerpc: 88,
+        sparc: 38,
+        alias_of: None,
+        description: "Not a socket (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTSUP",
+        number: libc::ENOTSUP,
+        alpha: 45,
+        mips: 122,
+        powerpc: 95,
+        sparc: 45,
+        alias_of: None,
+        description: "Operation not supported (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTTY",
+        number: libc::ENOTTY,
+        alpha: 25,
+        mips: 25,
+        powerpc: 25,
+        sparc: 25,
+        alias_of: None,
+        description: "Inappropriate I/O control operation (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ENOTUNIQ",
+        number: libc::ENOTUNIQ,
+        alpha: 113,
+        mips: 80,
+        powerpc: 76,
+        sparc: 115,
+        alias_of: None,
+        description: "Name not unique on network.",
+    },
+    Entry {
+        name: "ENXIO",
+        number: libc::ENXIO,
+        alpha: 6,
+        mips: 6,
+        powerpc: 6,
+        sparc: 6,
+        alias_of: None,
+        description: "No such device or address (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EOPNOTSUPP",
+        number: libc::EOPNOTSUPP,
+        alpha: 45,
+        mips: 122,
+        powerpc: 95,
+        sparc: 45,
+        alias_of: Some("ENOTSUP"),
+        description: "Operation not supported on socket (POSIX.1-2001). (ENOTSUP and EOPNOTSUPP have the same value on Linux, but according to POSIX.1 these error values should be distinct.)",
+    },
+    Entry {
+        name: "EOVERFLOW",
+        number: libc::EOVERFLOW,
+        alpha: 112,
+        mips: 79,
+        powerpc: 75,
+        sparc: 92,
+        alias_of: None,
+        description: "Value too large to be stored in data type (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EOWNERDEAD",
+        number: libc::EOWNERDEAD,
+        alpha: 136,
+        mips: 165,
+        powerpc: 130,
+        sparc: 132,
+        alias_of: None,
+        description: "Owner died (POSIX.1-2008).",
+    },
+    Entry {
+        name: "EPERM",
+        number: libc::EPERM,
+        alpha: 1,
+        mips: 1,
+        powerpc: 1,
+        sparc: 1,
+        alias_of: None,
+        description: "Operation not permitted (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EPFNOSUPPORT",
+        number: libc::EPFNOSUPPORT,
+        alpha: 46,
+        mips: 123,
+        powerpc: 96,
+        sparc: 46,
+        alias_of: None,
+        description: "Protocol family not supported.",
+    },
+    Entry {
+        name: "EPIPE",
+        number: libc::EPIPE,
+        alpha: 32,
+        mips: 32,
+        powerpc: 32,
+        sparc: 32,
+        alias_of: None,
+        description: "Broken pipe (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EPROTO",
+        number: libc::EPROTO,
+        alpha: 85,
+        mips: 71,
+        powerpc: 71,
+        sparc: 86,
+        alias_of: None,
+        description: "Protocol error (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EPROTONOSUPPORT",
+        number: libc::EPROTONOSUPPORT,
+        alpha: 43,
+        mips: 120,
+        powerpc: 93,
+        sparc: 43,
+        alias_of: None,
+        description: "Protocol not supported (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EPROTOTYPE",
+        number: libc::EPROTOTYPE,
+        alpha: 41,
+        mips: 98,
+        powerpc: 91,
+        sparc: 41,
+        alias_of: None,
+        description: "Protocol wrong type for socket (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ERANGE",
+        number: libc::ERANGE,
+        alpha: 34,
+        mips: 34,
+        powerpc: 34,
+        sparc: 34,
+        alias_of: None,
+        description: "Result too large (POSIX.1, C99).",
+    },
+    Entry {
+        name: "EREMCHG",
+        number: libc::EREMCHG,
+        alpha: 115,
+        mips: 82,
+        powerpc: 78,
+        sparc: 89,
+        alias_of: None,
+        description: "Remote address changed.",
+    },
+    Entry {
+        name: "EREMOTE",
+        number: libc::EREMOTE,
+        alpha: 71,
+        mips: 66,
+        powerpc: 66,
+        sparc: 71,
+        alias_of: None,
+        description: "Object is remote.",
+    },
+    Entry {
+        name: "EREMOTEIO",
+        number: libc::EREMOTEIO,
+        alpha: 121,
+        mips: 140,
+        powerpc: 121,
+        sparc: 121,
+        alias_of: None,
+        description: "Remote I/O error.",
+    },
+    Entry {
+        name: "ERESTART",
+        number: libc::ERESTART,
+        alpha: 127,
+        mips: 91,
+        powerpc: 85,
+        sparc: 116,
+        alias_of: None,
+        description: "Interrupted system call should be restarted.",
+    },
+    Entry {
+        name: "ERFKILL",
+        number: libc::ERFKILL,
+        alpha: 138,
+        mips: 167,
+        powerpc: 132,
+        sparc: 134,
+        alias_of: None,
+        description: "Operation not possible due to RF-kill.",
+    },
+    Entry {
+        name: "EROFS",
+        number: libc::EROFS,
+        alpha: 30,
+        mips: 30,
+        powerpc: 30,
+        sparc: 30,
+        alias_of: None,
+        description: "Read-only filesystem (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ESHUTDOWN",
+        number: libc::ESHUTDOWN,
+        alpha: 58,
+        mips: 143,
+        powerpc: 108,
+        sparc: 58,
+        alias_of: None,
+        description: "Cannot send after transport endpoint shutdown.",
+    },
+    Entry {
+        name: "ESPIPE",
+        number: libc::ESPIPE,
+        alpha: 29,
+        mips: 29,
+        powerpc: 29,
+        sparc: 29,
+        alias_of: None,
+        description: "Invalid seek (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ESOCKTNOSUPPORT",
+        number: libc::ESOCKTNOSUPPORT,
+        alpha: 44,
+        mips: 121,
+        powerpc: 94,
+        sparc: 44,
+        alias_of: None,
+        description: "Socket type not supported.",
+    },
+    Entry {
+        name: "ESRCH",
+        number: libc::ESRCH,
+        alpha: 3,
+        mips: 3,
+        powerpc: 3,
+        sparc: 3,
+        alias_of: None,
+        description: "No such process (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ESTALE",
+        number: libc::ESTALE,
+        alpha: 70,
+        mips: 151,
+        powerpc: 116,
+        sparc: 70,
+        alias_of: None,
+        description: "Stale file handle (POSIX.1-2001). This error can occur for NFS and for other filesystems.",
+    },
+    Entry {
+        name: "ESTRPIPE",
+        number: libc::ESTRPIPE,
+        alpha: 128,
+        mips: 92,
+        powerpc: 86,
+        sparc: 91,
+        alias_of: None,
+        description: "Streams pipe error.",
+    },
+    Entry {
+        name: "ETIME",
+        number: libc::ETIME,
+        alpha: 83,
+        mips: 62,
+        powerpc: 62,
+        sparc: 73,
+        alias_of: None,
+        description: "Timer expired (POSIX.1 (XSI STREAMS option)). (POSIX.1 says \"STREAM ioctl(2) timeout\".)",
+    },
+    Entry {
+        name: "ETIMEDOUT",
+        number: libc::ETIMEDOUT,
+        alpha: 60,
+        mips: 145,
+        powerpc: 110,
+        sparc: 60,
+        alias_of: None,
+        description: "Connection timed out (POSIX.1-2001).",
+    },
+    Entry {
+        name: "ETOOMANYREFS",
+        number: libc::ETOOMANYREFS,
+        alpha: 59,
+        mips: 144,
+        powerpc: 109,
+        sparc: 59,
+        alias_of: None,
+        description: "Too many references: cannot splice.",
+    },
+    Entry {
+        name: "ETXTBSY",
+        number: libc::ETXTBSY,
+        alpha: 26,
+        mips: 26,
+        powerpc: 26,
+        sparc: 26,
+        alias_of: None,
+        description: "Text file busy (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EUCLEAN",
+        number: libc::EUCLEAN,
+        alpha: 117,
+        mips: 135,
+        powerpc: 117,
+        sparc: 117,
+        alias_of: None,
+        description: "Structure needs cleaning.",
+    },
+    Entry {
+        name: "EUNATCH",
+        number: libc::EUNATCH,
+        alpha: 94,
+        mips: 42,
+        powerpc: 49,
+        sparc: 99,
+        alias_of: None,
+        description: "Protocol driver not attached.",
+    },
+    Entry {
+        name: "EUSERS",
+        number: libc::EUSERS,
+        alpha: 68,
+        mips: 94,
+        powerpc: 87,
+        sparc: 68,
+        alias_of: None,
+        description: "Too many users.",
+    },
+    Entry {
+        name: "EWOULDBLOCK",
+        number: libc::EWOULDBLOCK,
+        alpha: 35,
+        mips: 11,
+        powerpc: 11,
+        sparc: 11,
+        alias_of: Some("EAGAIN"),
+        description: "Operation would block (may be same value as EAGAIN) (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EXDEV",
+        number: libc::EXDEV,
+        alpha: 18,
+        mips: 18,
+        powerpc: 18,
+        sparc: 18,
+        alias_of: None,
+        description: "Improper link (POSIX.1-2001).",
+    },
+    Entry {
+        name: "EXFULL",
+        number: libc::EXFULL,
+        alpha: 99,
+        mips: 52,
+        powerpc: 54,
+        sparc: 104,
+        alias_of: None,
+        description: "Exchange full.",
+    },
+];
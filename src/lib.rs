@@ -0,0 +1,37 @@
+//! Lookup tables for ASCII codes, Linux signals and Linux error numbers (errno).
+
+/// Declares items that only exist on Linux, on the architectures the tables are checked against
+macro_rules! cfg_linux {
+    ($($item:item)*) => {
+        $(
+            #[cfg(all(
+                target_os = "linux",
+                any(
+                    target_arch = "x86_64",
+                    target_arch = "x86",
+                    target_arch = "arm",
+                    target_arch = "aarch64"
+                )
+            ))]
+            $item
+        )*
+    };
+}
+
+pub mod arch;
+pub mod ascii;
+#[cfg(feature = "cli")]
+pub mod cli;
+pub mod os;
+pub mod sysexits;
+
+cfg_linux! {
+    pub mod annotate;
+    pub mod errno;
+    mod search;
+    pub mod sigmask;
+    pub mod signal;
+    pub mod strace;
+    pub mod syscall;
+    pub mod trace;
+}
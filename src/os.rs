@@ -0,0 +1,39 @@
+//! Operating systems whose signal and error numbers can be looked up besides Linux.
+
+/// An operating system with its own signal and error numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Os {
+    Linux,
+    Freebsd,
+    #[cfg_attr(feature = "cli", value(alias = "darwin"))]
+    Macos,
+    Openbsd,
+    #[cfg_attr(feature = "cli", value(alias = "solaris"))]
+    Illumos,
+    Wasi,
+}
+
+impl Os {
+    /// All the operating systems, Linux first
+    pub const ALL: [Os; 6] = [
+        Os::Linux,
+        Os::Freebsd,
+        Os::Macos,
+        Os::Openbsd,
+        Os::Illumos,
+        Os::Wasi,
+    ];
+
+    /// Returns the lowercase name (e.g. "macos")
+    pub fn name(self) -> &'static str {
+        match self {
+            Os::Linux => "linux",
+            Os::Freebsd => "freebsd",
+            Os::Macos => "macos",
+            Os::Openbsd => "openbsd",
+            Os::Illumos => "illumos",
+            Os::Wasi => "wasi",
+        }
+    }
+}
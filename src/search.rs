@@ -0,0 +1,63 @@
+use std::cmp::Reverse;
+
+/// Returns the entries whose texts match any of the words: those matching the most words first,
+/// and among them the phrase matches first
+///
+/// Matching is case-insensitive. Each word may contain several whitespace-separated words.
+pub(crate) fn search<T, S: AsRef<str>>(
+    words: &[S],
+    entries: impl IntoIterator<Item = T>,
+    texts: impl Fn(&T) -> Vec<String>,
+) -> Vec<T> {
+    let words = split_words(words);
+    let phrase = words.join(" ");
+
+    let mut matches: Vec<(usize, bool, T)> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let texts: Vec<String> = texts(&entry)
+                .iter()
+                .map(|text| text.to_ascii_lowercase())
+                .collect();
+            let matched = words
+                .iter()
+                .filter(|word| texts.iter().any(|text| text.contains(word.as_str())))
+                .count();
+            let phrase_matched = texts.iter().any(|text| text.contains(&phrase));
+            (matched > 0).then_some((matched, phrase_matched, entry))
+        })
+        .collect();
+
+    // stable, so entries that match equally well keep their table order
+    matches.sort_by_key(|(matched, phrase_matched, _)| (Reverse(*matched), !phrase_matched));
+    matches.into_iter().map(|(_, _, entry)| entry).collect()
+}
+
+/// Splits the search words on whitespace and lowercases them
+pub(crate) fn split_words<S: AsRef<str>>(words: &[S]) -> Vec<String> {
+    words
+        .iter()
+        .flat_map(|word| word.as_ref().split_whitespace())
+        .map(|word| word.to_ascii_lowercase())
+        .collect()
+}
+
+/// Returns the candidates closest to a (possibly misspelled) name, ignoring case
+pub(crate) fn suggest(name: &str, candidates: Vec<String>) -> Vec<String> {
+    // allow roughly one typo (including swapped letters) per three characters
+    let max_distance = (name.len() / 3).max(1);
+
+    let name = name.to_ascii_uppercase();
+    let mut suggestions: Vec<(usize, String)> = candidates
+        .into_iter()
+        .map(|candidate| (strsim::osa_distance(&name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    let closest = suggestions.iter().map(|(distance, _)| *distance).min();
+    suggestions.retain(|(distance, _)| Some(*distance) == closest);
+    suggestions
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
@@ -0,0 +1,143 @@
+//! Signal masks of processes, as shown in hexadecimal in `/proc/<pid>/status`.
+
+use libc::c_int;
+use std::{fs, io};
+
+/// The signal masks of a process, read from `/proc/<pid>/status`
+pub struct Masks {
+    /// The name of the command (`Name`)
+    pub name: String,
+    /// Signals pending for the thread (`SigPnd`)
+    pub pending: u64,
+    /// Signals pending for the whole process (`ShdPnd`)
+    pub shared_pending: u64,
+    /// `SigBlk`
+    pub blocked: u64,
+    /// `SigIgn`
+    pub ignored: u64,
+    /// Signals with a handler (`SigCgt`)
+    pub caught: u64,
+}
+
+impl Masks {
+    /// Reads the masks of the process
+    pub fn read(pid: u32) -> io::Result<Self> {
+        let status = fs::read_to_string(format!("/proc/{}/status", pid))?;
+        Self::parse(&status).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "no signal masks in the status")
+        })
+    }
+
+    /// Parses the contents of `/proc/<pid>/status`
+    pub fn parse(status: &str) -> Option<Self> {
+        let mask = |name: &str| field(status, name).and_then(parse);
+        Some(Self {
+            name: field(status, "Name").unwrap_or_default().to_owned(),
+            pending: mask("SigPnd")?,
+            shared_pending: mask("ShdPnd")?,
+            blocked: mask("SigBlk")?,
+            ignored: mask("SigIgn")?,
+            caught: mask("SigCgt")?,
+        })
+    }
+}
+
+/// A process with its signal masks
+pub struct Process {
+    pub pid: u32,
+    pub masks: Masks,
+    /// The cgroup v2 path of the process, or its systemd one on cgroup v1 (e.g. "/system.slice/nginx.service")
+    pub cgroup: String,
+}
+
+/// Reads the masks of every process, in ascending order of pid
+///
+/// Kernel threads, which ignore every signal, are left out, as are the processes
+/// that exit while they are read.
+pub fn processes() -> io::Result<Vec<Process>> {
+    let mut pids: Vec<u32> = fs::read_dir("/proc")?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    pids.sort_unstable();
+
+    let mut processes = Vec::new();
+    for pid in pids {
+        let status = match fs::read_to_string(format!("/proc/{}/status", pid)) {
+            Ok(status) => status,
+            Err(_) => continue,
+        };
+        let masks = match Masks::parse(&status) {
+            Some(masks) if !is_kernel_thread(pid, &status) => masks,
+            _ => continue,
+        };
+        let cgroup = fs::read_to_string(format!("/proc/{}/cgroup", pid))
+            .map(|cgroups| cgroup(&cgroups).to_owned())
+            .unwrap_or_default();
+        processes.push(Process { pid, masks, cgroup });
+    }
+    Ok(processes)
+}
+
+/// Whether the status is the one of a kernel thread: kthreadd (pid 2) or one of its children
+/// (the `Kthread` field only exists since Linux 6.0)
+fn is_kernel_thread(pid: u32, status: &str) -> bool {
+    pid == 2 || field(status, "Kthread") == Some("1") || field(status, "PPid") == Some("2")
+}
+
+/// Returns the value of a field in the contents of `/proc/<pid>/status`
+fn field<'a>(status: &'a str, name: &str) -> Option<&'a str> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+        .map(str::trim)
+}
+
+/// Picks the path of the unified hierarchy in the contents of `/proc/<pid>/cgroup`,
+/// falling back to the systemd one, then to the first one
+fn cgroup(cgroups: &str) -> &str {
+    let paths: Vec<(&str, &str)> = cgroups
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(':')?;
+            rest.split_once(':')
+        })
+        .collect();
+    paths
+        .iter()
+        .find(|(controllers, _)| controllers.is_empty())
+        .or_else(|| {
+            paths
+                .iter()
+                .find(|(controllers, _)| *controllers == "name=systemd")
+        })
+        .or_else(|| paths.first())
+        .map_or("", |(_, path)| path)
+}
+
+/// Parses a mask written in hexadecimal, with or without "0x" (e.g. "0000000000004002")
+pub fn parse(hex: &str) -> Option<u64> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    u64::from_str_radix(hex, 16).ok()
+}
+
+/// Formats a mask like `/proc/<pid>/status` does, as 16 hexadecimal digits
+pub fn format(mask: u64) -> String {
+    format!("{:016x}", mask)
+}
+
+/// Returns the mask of the signals, or `None` if a number is out of the 1 to 64 range
+pub fn from_numbers(numbers: impl IntoIterator<Item = c_int>) -> Option<u64> {
+    numbers.into_iter().try_fold(0, |mask, signum| {
+        (1..=64).contains(&signum).then(|| mask | 1 << (signum - 1))
+    })
+}
+
+/// Returns the signal numbers in the mask, in ascending order; bit 0 stands for signal 1
+pub fn numbers(mask: u64) -> Vec<c_int> {
+    (1..=64).filter(|&signum| contains(mask, signum)).collect()
+}
+
+/// Whether the mask contains the signal
+pub fn contains(mask: u64, signum: c_int) -> bool {
+    (1..=64).contains(&signum) && mask & (1 << (signum - 1)) != 0
+}
@@ -1,280 +1,421 @@
-#![cfg(all(
-    target_os = "linux",
-    any(
-        target_arch = "x86_64",
-        target_arch = "x86",
-        target_arch = "arm",
-        target_arch = "aarch64"
-    )
-))]
-
-use box_drawing_table::{ansi_term::Style, Align, Border, Cell, Column, Row, Table};
-use clap::Parser;
+use crate::arch::Arch;
+use crate::os::Os;
 use libc::{c_int, strsignal};
 use std::ffi::CStr;
 
-// NOTE: based on Linux man-pages 6.01
-
-/// A simple utility to look up Linux signals
-#[derive(Debug, Parser)]
-#[clap(author, version, about, long_about = None)]
-struct Args {
-    #[clap(value_parser)]
-    /// signal number (e.g. "2"),
-    /// signal name (e.g. "SIGINT"),
-    /// or shell status code (e.g. "130") if "-s" option is specified
-    query: Vec<String>,
-
-    #[clap(long, default_value_t = false)]
-    /// Disable pretty-printing
-    simple: bool,
-
-    #[clap(short, long, default_value_t = false)]
-    /// List all signals
-    list: bool,
-
-    #[clap(short, long, default_value_t = false)]
-    /// Interpret numbers as status code instead of signal number
-    status: bool,
-
-    #[clap(long, default_value_t = false)]
-    /// Display the description using strsignal(3)
-    libc: bool,
-}
-
-fn main() {
-    let args = Args::parse();
-
-    let mut rows = Vec::new();
+mod code;
+mod os;
+mod realtime;
+mod table;
 
-    let queries = if args.list { list() } else { args.query };
-    for q in queries {
-        let sigdesc = if let Ok(mut num) = q.parse::<c_int>() {
-            if args.status {
-                num -= 128;
-            }
-            SignalDesc::from_number(num).ok_or(num)
-        } else {
-            Ok(SignalDesc::from_name(q.clone()))
-        };
+/// The first real-time signal of the kernel, which glibc keeps for itself along with the next one
+const KERNEL_SIGRTMIN: c_int = 32;
 
-        let name: String;
-        let number: String;
-        let description: String;
-        match sigdesc {
-            Ok(sigdesc) => {
-                name = sigdesc.name().to_string();
-                number = sigdesc
-                    .number()
-                    .map(|n| n.to_string())
-                    .unwrap_or_else(|| "-".to_owned());
-
-                if args.libc {
-                    description = sigdesc.libc_descripton();
-                } else {
-                    description = sigdesc.manpages_description();
-                }
-            }
-            Err(num) => {
-                name = "-".to_owned();
-                number = num.to_string();
-                description = "Unknown signal".to_owned();
-            }
-        }
+/// A row of the signal table
+struct Entry {
+    name: &'static str,
+    /// The number on the generic architectures (i.e. the host), `None` for signals not used on Linux
+    number: Option<c_int>,
+    // the numbers on the other architectures, from the numbering table of signal(7)
+    alpha: Option<c_int>,
+    mips: Option<c_int>,
+    powerpc: Option<c_int>,
+    sparc: Option<c_int>,
+    /// The name this one is a synonym for; synonyms are only returned by number
+    /// where they have a number of their own (e.g. EDEADLOCK on MIPS)
+    alias_of: Option<&'static str>,
+    /// The standard that introduced the signal ("P1990" or "P2001"), as listed in signal(7)
+    standard: Option<&'static str>,
+    /// The default action ("Term", "Ign", "Core", "Stop" or "Cont"), as listed in signal(7)
+    action: Option<&'static str>,
+    description: &'static str,
+}
 
-        if args.simple {
-            println!("{} {} {}", name, number, description);
-        } else {
-            rows.push(Row::flexible_height(vec![
-                Cell {
-                    value: name,
-                    align: Align::Left,
-                    style: Style::default().bold(),
-                },
-                Cell::left(number),
-                Cell::left(description),
-            ]));
+impl Entry {
+    /// Returns the number on the architecture, `None` if the signal is not used there
+    fn number_on(&self, arch: Arch) -> Option<c_int> {
+        match arch {
+            Arch::Generic => self.number,
+            Arch::Alpha => self.alpha,
+            Arch::Mips => self.mips,
+            Arch::Powerpc => self.powerpc,
+            Arch::Sparc => self.sparc,
         }
     }
+}
+
+/// A value of `si_code`, which tells why a signal was sent (e.g. SEGV_MAPERR for a SIGSEGV)
+pub struct Code {
+    pub name: &'static str,
+    pub value: c_int,
+    /// The description taken from sigaction(2)
+    pub description: &'static str,
+}
 
-    if !args.simple && !rows.is_empty() {
-        create_table(rows);
+/// A row of the signal table of another system: name, number, default action, and the description
+/// where Linux has no such name or gives it another meaning
+type Foreign = (&'static str, c_int, &'static str, Option<&'static str>);
+
+/// Returns the signal table of another system
+fn foreign(os: Os) -> &'static [Foreign] {
+    match os {
+        Os::Linux => &[],
+        Os::Freebsd => os::FREEBSD,
+        Os::Macos => os::MACOS,
+        Os::Openbsd => os::OPENBSD,
+        Os::Illumos => os::ILLUMOS,
+        Os::Wasi => os::WASI,
     }
 }
 
-fn create_table(rows: Vec<Row>) {
-    let mut table = Table::new(vec![
-        Border::Double.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Single.into(),
-        Column::flexible_width(),
-        Border::Double.into(),
-    ]);
-
-    table.append_row(Border::Double.into());
-    table.append_row(Row::flexible_height(vec![
-        Cell::left("name"),
-        Cell::left("number"),
-        Cell::left("description"),
-    ]));
-    table.append_row(Border::Single.into());
-
-    for r in rows {
-        table.append_row(r);
+/// Returns the names of all known signals
+pub fn list() -> Vec<String> {
+    list_in(Os::Linux)
+}
+
+/// Returns the names of all known signals of the system
+pub fn list_in(os: Os) -> Vec<String> {
+    all_in(os).map(|desc| desc.name().to_owned()).collect()
+}
+
+/// Returns the names of all known Linux signals, with the real-time ones of the architecture
+pub fn list_on(arch: Arch) -> Vec<String> {
+    linux_on(arch).map(|entry| entry.name.to_owned()).collect()
+}
+
+/// Searches the man-pages and strsignal(3) descriptions of all signals for the words
+///
+/// Every signal matching a word is returned, those matching the most words first,
+/// and among them those containing the whole phrase first.
+pub fn search<S: AsRef<str>>(words: &[S]) -> Vec<SignalDesc> {
+    search_in(Os::Linux, words)
+}
+
+/// Searches the descriptions of all signals of the system for the words, like [`search`]
+pub fn search_in<S: AsRef<str>>(os: Os, words: &[S]) -> Vec<SignalDesc> {
+    crate::search::search(words, all_in(os), |desc| {
+        vec![desc.manpages_description(), desc.libc_description()]
+    })
+}
+
+/// Returns the known names closest to a misspelled one (e.g. SIGTERM for "SIGTREM")
+pub fn suggest(name: &str) -> Vec<String> {
+    suggest_in(Os::Linux, name)
+}
+
+/// Returns the names of the system closest to a misspelled one
+pub fn suggest_in(os: Os, name: &str) -> Vec<String> {
+    crate::search::suggest(name, list_in(os))
+}
+
+/// The signals that cannot be caught, blocked or ignored
+const UNCATCHABLE: &[&str] = &["SIGKILL", "SIGSTOP"];
+
+/// The signals usually raised by the faulting instruction of the thread that receives them
+const SYNCHRONOUS: &[&str] = &[
+    "SIGBUS", "SIGEMT", "SIGFPE", "SIGILL", "SIGSEGV", "SIGSYS", "SIGTRAP",
+];
+
+/// Returns the Linux signals of the generic architectures: the standard ones, then the real-time ones
+fn linux() -> impl Iterator<Item = &'static Entry> {
+    linux_on(Arch::Generic)
+}
+
+/// Returns the Linux signals, with the real-time ones of the architecture
+fn linux_on(arch: Arch) -> impl Iterator<Item = &'static Entry> {
+    let realtime = realtime::table()
+        .iter()
+        .filter(move |entry| entry.number_on(arch).is_some());
+    table::TABLE.iter().chain(realtime)
+}
+
+/// Returns the SIGRTMAX of the kernel on the architecture, one more than the one of glibc on MIPS
+fn kernel_sigrtmax(arch: Arch) -> c_int {
+    match arch {
+        Arch::Mips => 128,
+        _ => 64,
     }
+}
+
+/// Returns the number of a real-time signal named relative to the limits of glibc
+/// (e.g. "SIGRTMIN+3") or to those of the kernel (e.g. "__SIGRTMIN+3") on the architecture
+fn realtime_number(arch: Arch, name: &str) -> Option<c_int> {
+    let (min, max, name) = match name.strip_prefix("__") {
+        Some(name) => (KERNEL_SIGRTMIN, kernel_sigrtmax(arch), name),
+        None => (realtime::SIGRTMIN, realtime::sigrtmax(arch), name),
+    };
+    let offset = |rest: &str, sign: char| match rest {
+        "" => Some(0),
+        _ => rest.strip_prefix(sign)?.parse::<c_int>().ok(),
+    };
+    let number = if let Some(rest) = name.strip_prefix("SIGRTMIN") {
+        min + offset(rest, '+')?
+    } else if let Some(rest) = name.strip_prefix("SIGRTMAX") {
+        max - offset(rest, '-')?
+    } else {
+        return None;
+    };
+    (min..=max).contains(&number).then_some(number)
+}
 
-    table.append_row(Border::Double.into());
-    print!("{}", table);
+/// Returns the values of `si_code` any signal may carry on the architecture (e.g. SI_USER)
+pub fn any_codes(arch: Arch) -> &'static [Code] {
+    match arch {
+        Arch::Mips => code::ANY_MIPS,
+        _ => code::ANY,
+    }
 }
 
-fn list() -> Vec<String> {
-    [
-        "SIGABRT",
-        "SIGALRM",
-        "SIGBUS",
-        "SIGCHLD",
-        "SIGCLD",
-        "SIGCONT",
-        "SIGEMT",
-        "SIGFPE",
-        "SIGHUP",
-        "SIGILL",
-        "SIGINFO",
-        "SIGINT",
-        "SIGIO",
-        "SIGIOT",
-        "SIGKILL",
-        "SIGLOST",
-        "SIGPIPE",
-        "SIGPOLL",
-        "SIGPROF",
-        "SIGPWR",
-        "SIGQUIT",
-        "SIGSEGV",
-        "SIGSTKFLT",
-        "SIGSTOP",
-        "SIGTSTP",
-        "SIGSYS",
-        "SIGTERM",
-        "SIGTRAP",
-        "SIGTTIN",
-        "SIGTTOU",
-        "SIGUNUSED",
-        "SIGURG",
-        "SIGUSR1",
-        "SIGUSR2",
-        "SIGVTALRM",
-        "SIGXCPU",
-        "SIGXFSZ",
-        "SIGWINCH",
-    ]
-    .iter()
-    .map(|name| name.to_string())
-    .collect()
+/// Returns every signal of the system
+fn all_in(os: Os) -> Box<dyn Iterator<Item = SignalDesc>> {
+    match os {
+        Os::Linux => Box::new(linux().map(SignalDesc::new)),
+        _ => Box::new(
+            foreign(os)
+                .iter()
+                .map(move |row| SignalDesc::foreign(os, row)),
+        ),
+    }
 }
 
-struct SignalDesc {
-    name: String,
+/// A Linux signal, identified by its symbolic name
+///
+/// Numbers are those of the generic architectures unless another one is chosen with [`SignalDesc::on`],
+/// or those of another system for the signals looked up with [`SignalDesc::from_name_in`]
+/// and [`SignalDesc::from_number_in`].
+pub struct SignalDesc {
+    /// The Linux entry of the same name, if any
+    entry: Option<&'static Entry>,
+    /// The entry of the other system, if the signal is not a Linux one
+    foreign: Option<&'static Foreign>,
+    os: Os,
+    arch: Arch,
 }
 
 impl SignalDesc {
-    fn from_name(name: String) -> Self {
-        Self { name }
+    /// Looks up the signal by its symbolic name (e.g. "SIGINT")
+    ///
+    /// Real-time signals may be named with any offset, from the SIGRTMIN of glibc (e.g. "SIGRTMIN+20")
+    /// or from the one of the kernel (e.g. "__SIGRTMIN+2", which is glibc's SIGRTMIN).
+    /// Returns `None` for unknown names; see [`suggest`] for the closest known ones.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::from_name_on(Arch::Generic, name)
+    }
+
+    /// Looks up the signal by its symbolic name on the architecture, like [`SignalDesc::from_name`]
+    ///
+    /// The offsets of real-time signals are relative to the limits of the architecture
+    /// (e.g. "SIGRTMIN+20" is 54 on all of them, but "SIGRTMAX-1" is 126 on MIPS).
+    pub fn from_name_on(arch: Arch, name: &str) -> Option<Self> {
+        match linux_on(arch).find(|entry| entry.name == name) {
+            Some(entry) => Some(Self::new(entry).on(arch)),
+            None => Self::from_number_on(arch, realtime_number(arch, name)?),
+        }
+    }
+
+    /// Looks up the signal by its symbolic name on the system
+    pub fn from_name_in(os: Os, name: &str) -> Option<Self> {
+        match os {
+            Os::Linux => Self::from_name(name),
+            _ => all_in(os).find(|desc| desc.name() == name),
+        }
     }
 
-    fn from_number(signum: c_int) -> Option<Self> {
-        let name = match signum {
-            libc::SIGABRT => "SIGABRT",
-            libc::SIGALRM => "SIGALRM",
-            libc::SIGBUS => "SIGBUS",
-            libc::SIGCHLD => "SIGCHLD",
-            libc::SIGCONT => "SIGCONT",
-            libc::SIGFPE => "SIGFPE",
-            libc::SIGHUP => "SIGHUP",
-            libc::SIGILL => "SIGILL",
-            libc::SIGINT => "SIGINT",
-            libc::SIGIO => "SIGIO",
-            libc::SIGKILL => "SIGKILL",
-            libc::SIGPIPE => "SIGPIPE",
-            libc::SIGPROF => "SIGPROF",
-            libc::SIGPWR => "SIGPWR",
-            libc::SIGQUIT => "SIGQUIT",
-            libc::SIGSEGV => "SIGSEGV",
-            libc::SIGSTKFLT => "SIGSTKFLT",
-            libc::SIGSTOP => "SIGSTOP",
-            libc::SIGTSTP => "SIGTSTP",
-            libc::SIGSYS => "SIGSYS",
-            libc::SIGTERM => "SIGTERM",
-            libc::SIGTRAP => "SIGTRAP",
-            libc::SIGTTIN => "SIGTTIN",
-            libc::SIGTTOU => "SIGTTOU",
-            libc::SIGURG => "SIGURG",
-            libc::SIGUSR1 => "SIGUSR1",
-            libc::SIGUSR2 => "SIGUSR2",
-            libc::SIGVTALRM => "SIGVTALRM",
-            libc::SIGXCPU => "SIGXCPU",
-            libc::SIGXFSZ => "SIGXFSZ",
-            libc::SIGWINCH => "SIGWINCH",
-            _ => None?,
+    /// Looks up the signal corresponding to the given signal number
+    pub fn from_number(signum: c_int) -> Option<Self> {
+        Self::from_number_on(Arch::Generic, signum)
+    }
+
+    /// Looks up the signal corresponding to the given signal number on the architecture
+    pub fn from_number_on(arch: Arch, signum: c_int) -> Option<Self> {
+        Self::preferred(linux_on(arch).map(|entry| Self::new(entry).on(arch)), signum)
+    }
+
+    /// Looks up the signal corresponding to the given signal number on the system
+    pub fn from_number_in(os: Os, signum: c_int) -> Option<Self> {
+        Self::preferred(all_in(os), signum)
+    }
+
+    /// Returns the first signal with the number, preferring those which are not synonyms
+    fn preferred(descs: impl Iterator<Item = Self>, signum: c_int) -> Option<Self> {
+        let mut found = descs.filter(|desc| desc.number() == Some(signum));
+        let first = found.next()?;
+        if first.alias_of().is_none() {
+            return Some(first);
+        }
+        found.find(|desc| desc.alias_of().is_none()).or(Some(first))
+    }
+
+    fn new(entry: &'static Entry) -> Self {
+        Self {
+            entry: Some(entry),
+            foreign: None,
+            os: Os::Linux,
+            arch: Arch::Generic,
+        }
+    }
+
+    fn foreign(os: Os, row: &'static Foreign) -> Self {
+        Self {
+            entry: linux().find(|entry| entry.name == row.0),
+            foreign: Some(row),
+            os,
+            arch: Arch::Generic,
         }
-        .to_owned();
-        Some(Self { name })
     }
 
-    fn name(&self) -> &str {
-        self.name.as_str()
+    /// Returns the same signal with the numbers of another architecture
+    ///
+    /// Architectures only matter on Linux; the signals of other systems are returned unchanged.
+    pub fn on(&self, arch: Arch) -> Self {
+        Self {
+            entry: self.entry,
+            foreign: self.foreign,
+            os: self.os,
+            arch: if self.foreign.is_some() {
+                Arch::Generic
+            } else {
+                arch
+            },
+        }
+    }
+
+    /// Returns the system of the numbers
+    pub fn os(&self) -> Os {
+        self.os
+    }
+
+    /// Returns the architecture of the numbers
+    pub fn arch(&self) -> Arch {
+        self.arch
+    }
+
+    /// Returns the symbolic name
+    pub fn name(&self) -> &str {
+        match (self.foreign, self.entry) {
+            (Some(row), _) => row.0,
+            (None, Some(entry)) => entry.name,
+            (None, None) => unreachable!("a signal comes from one of the tables"),
+        }
     }
 
-    fn number(&self) -> Option<c_int> {
-        let num = match self.name.as_str() {
-            "SIGABRT" => libc::SIGABRT,
-            "SIGALRM" => libc::SIGALRM,
-            "SIGBUS" => libc::SIGBUS,
-            "SIGCHLD" => libc::SIGCHLD,
-            "SIGCLD" => libc::SIGCHLD, // !
-            "SIGCONT" => libc::SIGCONT,
-            "SIGEMT" => return None, // !
-            "SIGFPE" => libc::SIGFPE,
-            "SIGHUP" => libc::SIGHUP,
-            "SIGILL" => libc::SIGILL,
-            "SIGINFO" => libc::SIGPWR, // !
-            "SIGINT" => libc::SIGINT,
-            "SIGIO" => libc::SIGIO,
-            "SIGIOT" => libc::SIGIOT,
-            "SIGKILL" => libc::SIGKILL,
-            "SIGLOST" => return None, // !
-            "SIGPIPE" => libc::SIGPIPE,
-            "SIGPOLL" => libc::SIGPOLL,
-            "SIGPROF" => libc::SIGPROF,
-            "SIGPWR" => libc::SIGPWR,
-            "SIGQUIT" => libc::SIGQUIT,
-            "SIGSEGV" => libc::SIGSEGV,
-            "SIGSTKFLT" => libc::SIGSTKFLT,
-            "SIGSTOP" => libc::SIGSTOP,
-            "SIGTSTP" => libc::SIGTSTP,
-            "SIGSYS" => libc::SIGSYS,
-            "SIGTERM" => libc::SIGTERM,
-            "SIGTRAP" => libc::SIGTRAP,
-            "SIGTTIN" => libc::SIGTTIN,
-            "SIGTTOU" => libc::SIGTTOU,
-            "SIGUNUSED" => libc::SIGSYS, // !
-            "SIGURG" => libc::SIGURG,
-            "SIGUSR1" => libc::SIGUSR1,
-            "SIGUSR2" => libc::SIGUSR2,
-            "SIGVTALRM" => libc::SIGVTALRM,
-            "SIGXCPU" => libc::SIGXCPU,
-            "SIGXFSZ" => libc::SIGXFSZ,
-            "SIGWINCH" => libc::SIGWINCH,
-            _ => return None,
+    /// Returns the signal number, or `None` if it is unknown or not used on the architecture
+    pub fn number(&self) -> Option<c_int> {
+        let entry = match (self.foreign, self.entry) {
+            (Some(row), _) => return Some(row.1),
+            (None, entry) => entry?,
         };
-        Some(num)
+        entry.number_on(self.arch)
+    }
+
+    /// Returns the name this one is a synonym for (e.g. SIGCHLD for SIGCLD)
+    pub fn alias_of(&self) -> Option<&str> {
+        let alias_of = self.entry?.alias_of?;
+        match self.foreign {
+            // only where the other system defines both with the same number
+            Some(row) => foreign(self.os)
+                .iter()
+                .any(|other| other.0 == alias_of && other.1 == row.1)
+                .then_some(alias_of),
+            None => Some(alias_of),
+        }
     }
 
-    fn libc_descripton(&self) -> String {
+    /// Returns the name the kernel gives a real-time signal (e.g. "SIGRTMIN+2" for glibc's SIGRTMIN),
+    /// or `None` for the other signals
+    pub fn kernel_name(&self) -> Option<String> {
+        if self.foreign.is_some() {
+            return None;
+        }
+        let number = self.number()?;
+        match number - KERNEL_SIGRTMIN {
+            offset if offset < 0 => None,
+            0 => Some("SIGRTMIN".to_owned()),
+            _ if number == kernel_sigrtmax(self.arch) => Some("SIGRTMAX".to_owned()),
+            offset => Some(format!("SIGRTMIN+{}", offset)),
+        }
+    }
+
+    /// Returns the standard that introduced the signal ("P1990" or "P2001"), if any
+    pub fn standard(&self) -> Option<&str> {
+        self.entry.and_then(|entry| entry.standard)
+    }
+
+    /// Returns the default action as signal(7) names it: "Term", "Ign", "Core", "Stop" or "Cont"
+    ///
+    /// The signals of other systems have the action of that system (e.g. "Ign" for SIGIO on FreeBSD).
+    pub fn action(&self) -> Option<&str> {
+        if let Some(row) = self.foreign {
+            return Some(row.2);
+        }
+        let entry = self.entry?;
+        entry.action.or_else(|| {
+            // e.g. SIGINFO, a synonym for SIGPWR
+            let alias_of = entry.alias_of?;
+            linux().find(|entry| entry.name == alias_of)?.action
+        })
+    }
+
+    /// Whether the signal can be caught, blocked or ignored, which all but SIGKILL and SIGSTOP can
+    pub fn catchable(&self) -> bool {
+        !UNCATCHABLE.contains(&self.name())
+    }
+
+    /// Whether the signal is usually synchronous: raised by a fault of the thread receiving it
+    /// (e.g. SIGSEGV) rather than sent by another process or the kernel
+    pub fn synchronous(&self) -> bool {
+        SYNCHRONOUS.contains(&self.name())
+            || self
+                .alias_of()
+                .is_some_and(|alias_of| SYNCHRONOUS.contains(&alias_of))
+    }
+
+    /// Returns the values of `si_code` the signal may carry: its own, then those of any signal
+    ///
+    /// Only Linux codes are known; the signals of other systems have none.
+    pub fn codes(&self) -> Vec<&'static Code> {
+        if self.foreign.is_some() {
+            return Vec::new();
+        }
+        let own = match self.alias_of().unwrap_or(self.name()) {
+            "SIGILL" => code::ILL,
+            "SIGFPE" => code::FPE,
+            "SIGSEGV" => code::SEGV,
+            "SIGBUS" => code::BUS,
+            "SIGTRAP" => code::TRAP,
+            "SIGCHLD" => code::CLD,
+            "SIGIO" => code::POLL,
+            "SIGSYS" => code::SYS,
+            _ => &[],
+        };
+        own.iter().chain(any_codes(self.arch)).collect()
+    }
+
+    /// Looks up a value of `si_code` for the signal (e.g. SEGV_MAPERR for 1 with SIGSEGV)
+    pub fn code(&self, value: c_int) -> Option<&'static Code> {
+        self.codes().into_iter().find(|code| code.value == value)
+    }
+
+    /// Returns the other names sharing the same signal number (e.g. SIGCLD for SIGCHLD)
+    pub fn aliases(&self) -> Vec<String> {
+        let num = match self.number() {
+            Some(num) => num,
+            None => return Vec::new(),
+        };
+        all_in(self.os)
+            .map(|desc| desc.on(self.arch))
+            .filter(|desc| desc.name() != self.name() && desc.number() == Some(num))
+            .map(|desc| desc.name().to_owned())
+            .collect()
+    }
+
+    /// Returns the description provided by strsignal(3) of the host
+    ///
+    /// The host knows the signals by their Linux numbers; signals Linux does not have are unknown to it.
+    pub fn libc_description(&self) -> String {
         let desc_ptr = self
-            .number()
+            .entry
+            .and_then(|entry| entry.number)
             .map(|num| unsafe { strsignal(num) })
             .unwrap_or(std::ptr::null_mut());
 
@@ -286,48 +427,22 @@ impl SignalDesc {
         }
     }
 
-    fn manpages_description(&self) -> String {
-        match self.name.as_str() {
-            "SIGABRT" => "Abort signal from abort(3)",
-            "SIGALRM" => "Timer signal from alarm(2)",
-            "SIGBUS" => "Bus error (bad memory access)",
-            "SIGCHLD" => "Child stopped or terminated",
-            "SIGCLD" => "A synonym for SIGCHLD",
-            "SIGCONT" => "Continue if stopped",
-            "SIGEMT" => "Emulator trap",
-            "SIGFPE" => "Floating-point exception",
-            "SIGHUP" => "Hangup detected on controlling terminal or death of controlling process",
-            "SIGILL" => "Illegal Instruction",
-            "SIGINFO" => "A synonym for SIGPWR",
-            "SIGINT" => "Interrupt from keyboard",
-            "SIGIO" => "I/O now possible (4.2BSD)",
-            "SIGIOT" => "IOT trap. A synonym for SIGABRT",
-            "SIGKILL" => "Kill signal",
-            "SIGLOST" => "File lock lost (unused)",
-            "SIGPIPE" => "Broken pipe: write to pipe with no readers; see pipe(7)",
-            "SIGPOLL" => "Pollable event (Sys V); synonym for SIGIO",
-            "SIGPROF" => "Profiling timer expired",
-            "SIGPWR" => "Power failure (System V)",
-            "SIGQUIT" => "Quit from keyboard",
-            "SIGSEGV" => "Invalid memory reference",
-            "SIGSTKFLT" => "Stack fault on coprocessor (unused)",
-            "SIGSTOP" => "Stop process",
-            "SIGTSTP" => "Stop typed at terminal",
-            "SIGSYS" => "Bad system call (SVr4); see also seccomp(2)",
-            "SIGTERM" => "Termination signal",
-            "SIGTRAP" => "Trace/breakpoint trap",
-            "SIGTTIN" => "Terminal input for background process",
-            "SIGTTOU" => "Terminal output for background process",
-            "SIGUNUSED" => "Synonymous with SIGSYS",
-            "SIGURG" => "Urgent condition on socket (4.2BSD)",
-            "SIGUSR1" => "User-defined signal 1",
-            "SIGUSR2" => "User-defined signal 2",
-            "SIGVTALRM" => "Virtual alarm clock (4.2BSD)",
-            "SIGXCPU" => "CPU time limit exceeded (4.2BSD); see setrlimit(2)",
-            "SIGXFSZ" => "File size limit exceeded (4.2BSD); see setrlimit(2)",
-            "SIGWINCH" => "Window resize signal (4.3BSD, Sun)",
-            _ => "Unknown signal",
+    /// Returns the description taken from the Linux man-pages
+    ///
+    /// Signals that Linux does not have, or that mean something else on the other system,
+    /// are described after the headers of that system.
+    pub fn manpages_description(&self) -> String {
+        match (self.foreign.and_then(|row| row.3), self.entry) {
+            (Some(description), _) => description.to_owned(),
+            (None, Some(entry)) => match self.kernel_name() {
+                Some(kernel_name) => {
+                    format!("{} (the kernel's {})", entry.description, kernel_name)
+                }
+                None => entry.description.to_owned(),
+            },
+            (None, None) => {
+                unreachable!("the other systems describe the signals Linux does not have")
+            }
         }
-        .to_owned()
     }
 }
@@ -0,0 +1,332 @@
+// NOTE: the values of `si_code` as listed in sigaction(2) of Linux man-pages 6.01,
+// with the numbers of the kernel headers (asm-generic/siginfo.h); not generated
+
+use super::Code;
+
+/// The codes any signal may carry, telling where it comes from
+pub(super) static ANY: &[Code] = &[
+    Code {
+        name: "SI_USER",
+        value: 0,
+        description: "Sent by kill(2)",
+    },
+    Code {
+        name: "SI_KERNEL",
+        value: 0x80,
+        description: "Sent by the kernel",
+    },
+    Code {
+        name: "SI_QUEUE",
+        value: -1,
+        description: "Sent by sigqueue(3)",
+    },
+    Code {
+        name: "SI_TIMER",
+        value: -2,
+        description: "POSIX timer expired",
+    },
+    Code {
+        name: "SI_MESGQ",
+        value: -3,
+        description: "POSIX message queue state changed; see mq_notify(3)",
+    },
+    Code {
+        name: "SI_ASYNCIO",
+        value: -4,
+        description: "AIO completed",
+    },
+    Code {
+        name: "SI_SIGIO",
+        value: -5,
+        description: "Queued SIGIO (only up to Linux 2.2)",
+    },
+    Code {
+        name: "SI_TKILL",
+        value: -6,
+        description: "Sent by tkill(2) or tgkill(2)",
+    },
+];
+
+/// [`ANY`] on MIPS, which numbers the codes of timers, message queues and AIO differently
+pub(super) static ANY_MIPS: &[Code] = &[
+    Code {
+        name: "SI_USER",
+        value: 0,
+        description: "Sent by kill(2)",
+    },
+    Code {
+        name: "SI_KERNEL",
+        value: 0x80,
+        description: "Sent by the kernel",
+    },
+    Code {
+        name: "SI_QUEUE",
+        value: -1,
+        description: "Sent by sigqueue(3)",
+    },
+    Code {
+        name: "SI_TIMER",
+        value: -3,
+        description: "POSIX timer expired",
+    },
+    Code {
+        name: "SI_MESGQ",
+        value: -4,
+        description: "POSIX message queue state changed; see mq_notify(3)",
+    },
+    Code {
+        name: "SI_ASYNCIO",
+        value: -2,
+        description: "AIO completed",
+    },
+    Code {
+        name: "SI_SIGIO",
+        value: -5,
+        description: "Queued SIGIO (only up to Linux 2.2)",
+    },
+    Code {
+        name: "SI_TKILL",
+        value: -6,
+        description: "Sent by tkill(2) or tgkill(2)",
+    },
+];
+
+/// SIGILL
+pub(super) static ILL: &[Code] = &[
+    Code {
+        name: "ILL_ILLOPC",
+        value: 1,
+        description: "Illegal opcode",
+    },
+    Code {
+        name: "ILL_ILLOPN",
+        value: 2,
+        description: "Illegal operand",
+    },
+    Code {
+        name: "ILL_ILLADR",
+        value: 3,
+        description: "Illegal addressing mode",
+    },
+    Code {
+        name: "ILL_ILLTRP",
+        value: 4,
+        description: "Illegal trap",
+    },
+    Code {
+        name: "ILL_PRVOPC",
+        value: 5,
+        description: "Privileged opcode",
+    },
+    Code {
+        name: "ILL_PRVREG",
+        value: 6,
+        description: "Privileged register",
+    },
+    Code {
+        name: "ILL_COPROC",
+        value: 7,
+        description: "Coprocessor error",
+    },
+    Code {
+        name: "ILL_BADSTK",
+        value: 8,
+        description: "Internal stack error",
+    },
+];
+
+/// SIGFPE
+pub(super) static FPE: &[Code] = &[
+    Code {
+        name: "FPE_INTDIV",
+        value: 1,
+        description: "Integer divide by zero",
+    },
+    Code {
+        name: "FPE_INTOVF",
+        value: 2,
+        description: "Integer overflow",
+    },
+    Code {
+        name: "FPE_FLTDIV",
+        value: 3,
+        description: "Floating-point divide by zero",
+    },
+    Code {
+        name: "FPE_FLTOVF",
+        value: 4,
+        description: "Floating-point overflow",
+    },
+    Code {
+        name: "FPE_FLTUND",
+        value: 5,
+        description: "Floating-point underflow",
+    },
+    Code {
+        name: "FPE_FLTRES",
+        value: 6,
+        description: "Floating-point inexact result",
+    },
+    Code {
+        name: "FPE_FLTINV",
+        value: 7,
+        description: "Floating-point invalid operation",
+    },
+    Code {
+        name: "FPE_FLTSUB",
+        value: 8,
+        description: "Subscript out of range",
+    },
+];
+
+/// SIGSEGV
+pub(super) static SEGV: &[Code] = &[
+    Code {
+        name: "SEGV_MAPERR",
+        value: 1,
+        description: "Address not mapped to object",
+    },
+    Code {
+        name: "SEGV_ACCERR",
+        value: 2,
+        description: "Invalid permissions for mapped object",
+    },
+    Code {
+        name: "SEGV_BNDERR",
+        value: 3,
+        description: "Failed address bound checks",
+    },
+    Code {
+        name: "SEGV_PKUERR",
+        value: 4,
+        description: "Access was denied by memory protection keys; see pkeys(7)",
+    },
+];
+
+/// SIGBUS
+pub(super) static BUS: &[Code] = &[
+    Code {
+        name: "BUS_ADRALN",
+        value: 1,
+        description: "Invalid address alignment",
+    },
+    Code {
+        name: "BUS_ADRERR",
+        value: 2,
+        description: "Nonexistent physical address",
+    },
+    Code {
+        name: "BUS_OBJERR",
+        value: 3,
+        description: "Object-specific hardware error",
+    },
+    Code {
+        name: "BUS_MCEERR_AR",
+        value: 4,
+        description: "Hardware memory error consumed on a machine check; action required",
+    },
+    Code {
+        name: "BUS_MCEERR_AO",
+        value: 5,
+        description: "Hardware memory error detected in process but not consumed; action optional",
+    },
+];
+
+/// SIGTRAP
+pub(super) static TRAP: &[Code] = &[
+    Code {
+        name: "TRAP_BRKPT",
+        value: 1,
+        description: "Process breakpoint",
+    },
+    Code {
+        name: "TRAP_TRACE",
+        value: 2,
+        description: "Process trace trap",
+    },
+    Code {
+        name: "TRAP_BRANCH",
+        value: 3,
+        description: "Process taken branch trap (IA64 only)",
+    },
+    Code {
+        name: "TRAP_HWBKPT",
+        value: 4,
+        description: "Hardware breakpoint/watchpoint (IA64 only)",
+    },
+];
+
+/// SIGCHLD
+pub(super) static CLD: &[Code] = &[
+    Code {
+        name: "CLD_EXITED",
+        value: 1,
+        description: "Child has exited",
+    },
+    Code {
+        name: "CLD_KILLED",
+        value: 2,
+        description: "Child was killed",
+    },
+    Code {
+        name: "CLD_DUMPED",
+        value: 3,
+        description: "Child terminated abnormally",
+    },
+    Code {
+        name: "CLD_TRAPPED",
+        value: 4,
+        description: "Traced child has trapped",
+    },
+    Code {
+        name: "CLD_STOPPED",
+        value: 5,
+        description: "Child has stopped",
+    },
+    Code {
+        name: "CLD_CONTINUED",
+        value: 6,
+        description: "Stopped child has continued",
+    },
+];
+
+/// SIGIO
+pub(super) static POLL: &[Code] = &[
+    Code {
+        name: "POLL_IN",
+        value: 1,
+        description: "Data input available",
+    },
+    Code {
+        name: "POLL_OUT",
+        value: 2,
+        description: "Output buffers available",
+    },
+    Code {
+        name: "POLL_MSG",
+        value: 3,
+        description: "Input message available",
+    },
+    Code {
+        name: "POLL_ERR",
+        value: 4,
+        description: "I/O error",
+    },
+    Code {
+        name: "POLL_PRI",
+        value: 5,
+        description: "High priority input available",
+    },
+    Code {
+        name: "POLL_HUP",
+        value: 6,
+        description: "Device disconnected",
+    },
+];
+
+/// SIGSYS
+pub(super) static SYS: &[Code] = &[Code {
+    name: "SYS_SECCOMP",
+    value: 1,
+    description: "Triggered by a seccomp(2) filter rule",
+}];
@@ -0,0 +1,209 @@
+// NOTE: numbers taken from the libc crate 0.2.190, default actions from the sigaction(2)
+// (signal.h(3HEAD) on illumos) and kernel sources of each system; descriptions are only
+// given for the names Linux does not have, or where the meaning differs from Linux
+
+use super::Foreign;
+
+/// FreeBSD
+pub(super) static FREEBSD: &[Foreign] = &[
+    ("SIGHUP", 1, "Term", None),
+    ("SIGINT", 2, "Term", None),
+    ("SIGQUIT", 3, "Core", None),
+    ("SIGILL", 4, "Core", None),
+    ("SIGTRAP", 5, "Core", None),
+    ("SIGABRT", 6, "Core", None),
+    ("SIGIOT", 6, "Core", None),
+    ("SIGEMT", 7, "Core", None),
+    ("SIGFPE", 8, "Core", None),
+    ("SIGKILL", 9, "Term", None),
+    ("SIGBUS", 10, "Core", None),
+    ("SIGSEGV", 11, "Core", None),
+    ("SIGSYS", 12, "Core", None),
+    ("SIGPIPE", 13, "Term", None),
+    ("SIGALRM", 14, "Term", None),
+    ("SIGTERM", 15, "Term", None),
+    ("SIGURG", 16, "Ign", None),
+    ("SIGSTOP", 17, "Stop", None),
+    ("SIGTSTP", 18, "Stop", None),
+    ("SIGCONT", 19, "Cont", None),
+    ("SIGCHLD", 20, "Ign", None),
+    ("SIGTTIN", 21, "Stop", None),
+    ("SIGTTOU", 22, "Stop", None),
+    ("SIGIO", 23, "Ign", None),
+    ("SIGXCPU", 24, "Core", None),
+    ("SIGXFSZ", 25, "Core", None),
+    ("SIGVTALRM", 26, "Term", None),
+    ("SIGPROF", 27, "Term", None),
+    ("SIGWINCH", 28, "Ign", None),
+    ("SIGINFO", 29, "Ign", Some("Status request from keyboard")),
+    ("SIGUSR1", 30, "Term", None),
+    ("SIGUSR2", 31, "Term", None),
+    ("SIGTHR", 32, "Term", Some("Thread interrupt")),
+    ("SIGLWP", 32, "Term", Some("A synonym for SIGTHR")),
+    ("SIGLIBRT", 33, "Term", Some("Real-time library interrupt")),
+];
+
+/// macOS
+pub(super) static MACOS: &[Foreign] = &[
+    ("SIGHUP", 1, "Term", None),
+    ("SIGINT", 2, "Term", None),
+    ("SIGQUIT", 3, "Core", None),
+    ("SIGILL", 4, "Core", None),
+    ("SIGTRAP", 5, "Core", None),
+    ("SIGABRT", 6, "Core", None),
+    ("SIGIOT", 6, "Core", None),
+    ("SIGEMT", 7, "Core", None),
+    ("SIGFPE", 8, "Core", None),
+    ("SIGKILL", 9, "Term", None),
+    ("SIGBUS", 10, "Core", None),
+    ("SIGSEGV", 11, "Core", None),
+    ("SIGSYS", 12, "Core", None),
+    ("SIGPIPE", 13, "Term", None),
+    ("SIGALRM", 14, "Term", None),
+    ("SIGTERM", 15, "Term", None),
+    ("SIGURG", 16, "Ign", None),
+    ("SIGSTOP", 17, "Stop", None),
+    ("SIGTSTP", 18, "Stop", None),
+    ("SIGCONT", 19, "Cont", None),
+    ("SIGCHLD", 20, "Ign", None),
+    ("SIGTTIN", 21, "Stop", None),
+    ("SIGTTOU", 22, "Stop", None),
+    ("SIGIO", 23, "Ign", None),
+    ("SIGXCPU", 24, "Core", None),
+    ("SIGXFSZ", 25, "Core", None),
+    ("SIGVTALRM", 26, "Term", None),
+    ("SIGPROF", 27, "Term", None),
+    ("SIGWINCH", 28, "Ign", None),
+    ("SIGINFO", 29, "Ign", Some("Status request from keyboard")),
+    ("SIGUSR1", 30, "Term", None),
+    ("SIGUSR2", 31, "Term", None),
+];
+
+/// OpenBSD
+pub(super) static OPENBSD: &[Foreign] = &[
+    ("SIGHUP", 1, "Term", None),
+    ("SIGINT", 2, "Term", None),
+    ("SIGQUIT", 3, "Core", None),
+    ("SIGILL", 4, "Core", None),
+    ("SIGTRAP", 5, "Core", None),
+    ("SIGABRT", 6, "Core", None),
+    ("SIGIOT", 6, "Core", None),
+    ("SIGEMT", 7, "Core", None),
+    ("SIGFPE", 8, "Core", None),
+    ("SIGKILL", 9, "Term", None),
+    ("SIGBUS", 10, "Core", None),
+    ("SIGSEGV", 11, "Core", None),
+    ("SIGSYS", 12, "Core", None),
+    ("SIGPIPE", 13, "Term", None),
+    ("SIGALRM", 14, "Term", None),
+    ("SIGTERM", 15, "Term", None),
+    ("SIGURG", 16, "Ign", None),
+    ("SIGSTOP", 17, "Stop", None),
+    ("SIGTSTP", 18, "Stop", None),
+    ("SIGCONT", 19, "Cont", None),
+    ("SIGCHLD", 20, "Ign", None),
+    ("SIGTTIN", 21, "Stop", None),
+    ("SIGTTOU", 22, "Stop", None),
+    ("SIGIO", 23, "Ign", None),
+    ("SIGXCPU", 24, "Core", None),
+    ("SIGXFSZ", 25, "Core", None),
+    ("SIGVTALRM", 26, "Term", None),
+    ("SIGPROF", 27, "Term", None),
+    ("SIGWINCH", 28, "Ign", None),
+    ("SIGINFO", 29, "Ign", Some("Status request from keyboard")),
+    ("SIGUSR1", 30, "Term", None),
+    ("SIGUSR2", 31, "Term", None),
+];
+
+/// illumos
+pub(super) static ILLUMOS: &[Foreign] = &[
+    ("SIGHUP", 1, "Term", None),
+    ("SIGINT", 2, "Term", None),
+    ("SIGQUIT", 3, "Core", None),
+    ("SIGILL", 4, "Core", None),
+    ("SIGTRAP", 5, "Core", None),
+    ("SIGABRT", 6, "Core", None),
+    ("SIGIOT", 6, "Core", None),
+    ("SIGEMT", 7, "Core", None),
+    ("SIGFPE", 8, "Core", None),
+    ("SIGKILL", 9, "Term", None),
+    ("SIGBUS", 10, "Core", None),
+    ("SIGSEGV", 11, "Core", None),
+    ("SIGSYS", 12, "Core", None),
+    ("SIGPIPE", 13, "Term", None),
+    ("SIGALRM", 14, "Term", None),
+    ("SIGTERM", 15, "Term", None),
+    ("SIGUSR1", 16, "Term", None),
+    ("SIGUSR2", 17, "Term", None),
+    ("SIGCHLD", 18, "Ign", None),
+    ("SIGCLD", 18, "Ign", None),
+    ("SIGPWR", 19, "Ign", None),
+    ("SIGWINCH", 20, "Ign", None),
+    ("SIGURG", 21, "Ign", None),
+    ("SIGPOLL", 22, "Term", None),
+    ("SIGIO", 22, "Term", None),
+    ("SIGSTOP", 23, "Stop", None),
+    ("SIGTSTP", 24, "Stop", None),
+    ("SIGCONT", 25, "Cont", None),
+    ("SIGTTIN", 26, "Stop", None),
+    ("SIGTTOU", 27, "Stop", None),
+    ("SIGVTALRM", 28, "Term", None),
+    ("SIGPROF", 29, "Term", None),
+    ("SIGXCPU", 30, "Core", None),
+    ("SIGXFSZ", 31, "Core", None),
+    ("SIGWAITING", 32, "Ign",
+        Some("Concurrency signal reserved by threads library"),
+    ),
+    ("SIGLWP", 33, "Ign",
+        Some("Inter-LWP signal reserved by threads library"),
+    ),
+    ("SIGFREEZE", 34, "Ign", Some("Check point freeze")),
+    ("SIGTHAW", 35, "Ign", Some("Check point thaw")),
+    ("SIGCANCEL", 36, "Ign",
+        Some("Cancellation signal reserved by threads library"),
+    ),
+    ("SIGLOST", 37, "Term", Some("Resource lost")),
+    ("SIGXRES", 38, "Ign", Some("Resource control exceeded")),
+    ("SIGJVM1", 39, "Ign", Some("Reserved for Java Virtual Machine 1")),
+    ("SIGJVM2", 40, "Ign", Some("Reserved for Java Virtual Machine 2")),
+    ("SIGINFO", 41, "Ign", Some("Information request")),
+];
+
+/// WASI, whose libc emulates the signals with the numbers and actions of Linux
+/// (they are not in the libc crate; taken from wasi-libc's `bits/signal.h`)
+pub(super) static WASI: &[Foreign] = &[
+    ("SIGHUP", 1, "Term", None),
+    ("SIGINT", 2, "Term", None),
+    ("SIGQUIT", 3, "Core", None),
+    ("SIGILL", 4, "Core", None),
+    ("SIGTRAP", 5, "Core", None),
+    ("SIGABRT", 6, "Core", None),
+    ("SIGIOT", 6, "Core", None),
+    ("SIGBUS", 7, "Core", None),
+    ("SIGFPE", 8, "Core", None),
+    ("SIGKILL", 9, "Term", None),
+    ("SIGUSR1", 10, "Term", None),
+    ("SIGSEGV", 11, "Core", None),
+    ("SIGUSR2", 12, "Term", None),
+    ("SIGPIPE", 13, "Term", None),
+    ("SIGALRM", 14, "Term", None),
+    ("SIGTERM", 15, "Term", None),
+    ("SIGSTKFLT", 16, "Term", None),
+    ("SIGCHLD", 17, "Ign", None),
+    ("SIGCONT", 18, "Cont", None),
+    ("SIGSTOP", 19, "Stop", None),
+    ("SIGTSTP", 20, "Stop", None),
+    ("SIGTTIN", 21, "Stop", None),
+    ("SIGTTOU", 22, "Stop", None),
+    ("SIGURG", 23, "Ign", None),
+    ("SIGXCPU", 24, "Core", None),
+    ("SIGXFSZ", 25, "Core", None),
+    ("SIGVTALRM", 26, "Term", None),
+    ("SIGPROF", 27, "Term", None),
+    ("SIGWINCH", 28, "Ign", None),
+    ("SIGPOLL", 29, "Term", None),
+    ("SIGIO", 29, "Term", None),
+    ("SIGPWR", 30, "Term", None),
+    ("SIGSYS", 31, "Core", None),
+    ("SIGUNUSED", 31, "Core", None),
+];
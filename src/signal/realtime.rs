@@ -0,0 +1,104 @@
+// NOTE: the real-time signals as named by glibc, whose SIGRTMIN is 34 since it keeps
+// the first two for itself (SIGCANCEL and SIGSETXID in its sources); not generated
+// by `cargo xtask tables`, as signal(7) only describes them in prose, but derived from
+// the limits of each architecture
+
+use super::{kernel_sigrtmax, Entry, KERNEL_SIGRTMIN};
+use crate::arch::Arch;
+use libc::c_int;
+use std::sync::OnceLock;
+
+/// The SIGRTMIN of glibc
+pub(super) const SIGRTMIN: c_int = KERNEL_SIGRTMIN + 2;
+
+/// Returns the SIGRTMAX of glibc on the architecture
+pub(super) fn sigrtmax(arch: Arch) -> c_int {
+    match arch {
+        Arch::Mips => 127,
+        _ => 64,
+    }
+}
+
+/// Returns the real-time signals of every architecture, in ascending order of their MIPS numbers
+/// (the only architecture with more of them); each has a number on the architectures
+/// where glibc gives it that name, and the last one only exists for the kernel of MIPS
+pub(super) fn table() -> &'static [Entry] {
+    static TABLE: OnceLock<Vec<Entry>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = vec![
+            reserved(
+                "SIGCANCEL",
+                KERNEL_SIGRTMIN,
+                "Real-time signal reserved by glibc for thread cancellation and timers",
+            ),
+            reserved(
+                "SIGSETXID",
+                KERNEL_SIGRTMIN + 1,
+                "Real-time signal reserved by glibc to apply setuid(2) and the like to every thread",
+            ),
+        ];
+        // every name is used on MIPS, which has the most real-time signals
+        for mips in SIGRTMIN..=sigrtmax(Arch::Mips) {
+            let name = name(Arch::Mips, mips);
+            let number = number(Arch::Generic, &name);
+            table.push(Entry {
+                name: Box::leak(name.into_boxed_str()),
+                number,
+                alpha: number,
+                mips: Some(mips),
+                powerpc: number,
+                sparc: number,
+                alias_of: None,
+                standard: None,
+                action: Some("Term"),
+                description: "Real-time signal with no predefined meaning",
+            });
+        }
+        // the kernel's SIGRTMAX on MIPS, which glibc leaves out as a wait status cannot report it
+        table.push(Entry {
+            name: "__SIGRTMAX",
+            number: None,
+            alpha: None,
+            mips: Some(kernel_sigrtmax(Arch::Mips)),
+            powerpc: None,
+            sparc: None,
+            alias_of: None,
+            standard: None,
+            action: Some("Term"),
+            description: "Real-time signal of the kernel that glibc does not use",
+        });
+        table
+    })
+}
+
+fn reserved(name: &'static str, number: c_int, description: &'static str) -> Entry {
+    Entry {
+        name,
+        number: Some(number),
+        alpha: Some(number),
+        mips: Some(number),
+        powerpc: Some(number),
+        sparc: Some(number),
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description,
+    }
+}
+
+/// Names a real-time signal like glibc and `kill -l` do: relative to SIGRTMIN up to halfway,
+/// then relative to SIGRTMAX (e.g. "SIGRTMIN+15" then "SIGRTMAX-14" on the generic architectures)
+fn name(arch: Arch, number: c_int) -> String {
+    let max = sigrtmax(arch);
+    match number - SIGRTMIN {
+        0 => "SIGRTMIN".to_owned(),
+        _ if number == max => "SIGRTMAX".to_owned(),
+        offset if offset <= (max - SIGRTMIN) / 2 => format!("SIGRTMIN+{}", offset),
+        _ => format!("SIGRTMAX-{}", max - number),
+    }
+}
+
+/// Returns the number glibc gives the name on the architecture, if it uses that name
+fn number(arch: Arch, name: &str) -> Option<c_int> {
+    (SIGRTMIN..=sigrtmax(arch)).find(|&number| self::name(arch, number) == name)
+}
@@ -0,0 +1,464 @@
+// NOTE: generated by `cargo xtask tables` from Linux man-pages 6.01
+// `number` and `alias_of` are kept when regenerating,
+// the other fields come from the man page
+
+use super::Entry;
+
+pub(super) static TABLE: &[Entry] = &[
+    Entry {
+        name: "SIGABRT",
+        number: Some(libc::SIGABRT),
+        alpha: Some(6),
+        mips: Some(6),
+        powerpc: Some(6),
+        sparc: Some(6),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Core"),
+        description: "Abort signal from abort(3)",
+    },
+    Entry {
+        name: "SIGALRM",
+        number: Some(libc::SIGALRM),
+        alpha: Some(14),
+        mips: Some(14),
+        powerpc: Some(14),
+        sparc: Some(14),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Timer signal from alarm(2)",
+    },
+    Entry {
+        name: "SIGBUS",
+        number: Some(libc::SIGBUS),
+        alpha: Some(10),
+        mips: Some(10),
+        powerpc: Some(7),
+        sparc: Some(10),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Core"),
+        description: "Bus error (bad memory access)",
+    },
+    Entry {
+        name: "SIGCHLD",
+        number: Some(libc::SIGCHLD),
+        alpha: Some(20),
+        mips: Some(18),
+        powerpc: Some(17),
+        sparc: Some(20),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Ign"),
+        description: "Child stopped or terminated",
+    },
+    Entry {
+        name: "SIGCLD",
+        number: Some(libc::SIGCHLD),
+        alpha: Some(20),
+        mips: Some(18),
+        powerpc: Some(17),
+        sparc: Some(20),
+        alias_of: Some("SIGCHLD"),
+        standard: None,
+        action: Some("Ign"),
+        description: "A synonym for SIGCHLD",
+    },
+    Entry {
+        name: "SIGCONT",
+        number: Some(libc::SIGCONT),
+        alpha: Some(19),
+        mips: Some(25),
+        powerpc: Some(18),
+        sparc: Some(19),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Cont"),
+        description: "Continue if stopped",
+    },
+    Entry {
+        name: "SIGEMT",
+        number: None,
+        alpha: Some(7),
+        mips: Some(7),
+        powerpc: None,
+        sparc: Some(7),
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description: "Emulator trap",
+    },
+    Entry {
+        name: "SIGFPE",
+        number: Some(libc::SIGFPE),
+        alpha: Some(8),
+        mips: Some(8),
+        powerpc: Some(8),
+        sparc: Some(8),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Core"),
+        description: "Floating-point exception",
+    },
+    Entry {
+        name: "SIGHUP",
+        number: Some(libc::SIGHUP),
+        alpha: Some(1),
+        mips: Some(1),
+        powerpc: Some(1),
+        sparc: Some(1),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Hangup detected on controlling terminal or death of controlling process",
+    },
+    Entry {
+        name: "SIGILL",
+        number: Some(libc::SIGILL),
+        alpha: Some(4),
+        mips: Some(4),
+        powerpc: Some(4),
+        sparc: Some(4),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Core"),
+        description: "Illegal Instruction",
+    },
+    Entry {
+        name: "SIGINFO",
+        number: Some(libc::SIGPWR),
+        alpha: Some(29),
+        mips: Some(19),
+        powerpc: Some(30),
+        sparc: None,
+        alias_of: Some("SIGPWR"),
+        standard: None,
+        action: None,
+        description: "A synonym for SIGPWR",
+    },
+    Entry {
+        name: "SIGINT",
+        number: Some(libc::SIGINT),
+        alpha: Some(2),
+        mips: Some(2),
+        powerpc: Some(2),
+        sparc: Some(2),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Interrupt from keyboard",
+    },
+    Entry {
+        name: "SIGIO",
+        number: Some(libc::SIGIO),
+        alpha: Some(23),
+        mips: Some(22),
+        powerpc: Some(29),
+        sparc: Some(23),
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description: "I/O now possible (4.2BSD)",
+    },
+    Entry {
+        name: "SIGIOT",
+        number: Some(libc::SIGIOT),
+        alpha: Some(6),
+        mips: Some(6),
+        powerpc: Some(6),
+        sparc: Some(6),
+        alias_of: Some("SIGABRT"),
+        standard: None,
+        action: Some("Core"),
+        description: "IOT trap. A synonym for SIGABRT",
+    },
+    Entry {
+        name: "SIGKILL",
+        number: Some(libc::SIGKILL),
+        alpha: Some(9),
+        mips: Some(9),
+        powerpc: Some(9),
+        sparc: Some(9),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Kill signal",
+    },
+    Entry {
+        name: "SIGLOST",
+        number: None,
+        alpha: None,
+        mips: None,
+        powerpc: None,
+        sparc: Some(29),
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description: "File lock lost (unused)",
+    },
+    Entry {
+        name: "SIGPIPE",
+        number: Some(libc::SIGPIPE),
+        alpha: Some(13),
+        mips: Some(13),
+        powerpc: Some(13),
+        sparc: Some(13),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Broken pipe: write to pipe with no readers; see pipe(7)",
+    },
+    Entry {
+        name: "SIGPOLL",
+        number: Some(libc::SIGPOLL),
+        alpha: Some(23),
+        mips: Some(22),
+        powerpc: Some(29),
+        sparc: Some(23),
+        alias_of: Some("SIGIO"),
+        standard: Some("P2001"),
+        action: Some("Term"),
+        description: "Pollable event (Sys V); synonym for SIGIO",
+    },
+    Entry {
+        name: "SIGPROF",
+        number: Some(libc::SIGPROF),
+        alpha: Some(27),
+        mips: Some(29),
+        powerpc: Some(27),
+        sparc: Some(27),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Term"),
+        description: "Profiling timer expired",
+    },
+    Entry {
+        name: "SIGPWR",
+        number: Some(libc::SIGPWR),
+        alpha: Some(29),
+        mips: Some(19),
+        powerpc: Some(30),
+        sparc: None,
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description: "Power failure (System V)",
+    },
+    Entry {
+        name: "SIGQUIT",
+        number: Some(libc::SIGQUIT),
+        alpha: Some(3),
+        mips: Some(3),
+        powerpc: Some(3),
+        sparc: Some(3),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Core"),
+        description: "Quit from keyboard",
+    },
+    Entry {
+        name: "SIGSEGV",
+        number: Some(libc::SIGSEGV),
+        alpha: Some(11),
+        mips: Some(11),
+        powerpc: Some(11),
+        sparc: Some(11),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Core"),
+        description: "Invalid memory reference",
+    },
+    Entry {
+        name: "SIGSTKFLT",
+        number: Some(libc::SIGSTKFLT),
+        alpha: None,
+        mips: None,
+        powerpc: Some(16),
+        sparc: None,
+        alias_of: None,
+        standard: None,
+        action: Some("Term"),
+        description: "Stack fault on coprocessor (unused)",
+    },
+    Entry {
+        name: "SIGSTOP",
+        number: Some(libc::SIGSTOP),
+        alpha: Some(17),
+        mips: Some(23),
+        powerpc: Some(19),
+        sparc: Some(17),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Stop"),
+        description: "Stop process",
+    },
+    Entry {
+        name: "SIGTSTP",
+        number: Some(libc::SIGTSTP),
+        alpha: Some(18),
+        mips: Some(24),
+        powerpc: Some(20),
+        sparc: Some(18),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Stop"),
+        description: "Stop typed at terminal",
+    },
+    Entry {
+        name: "SIGSYS",
+        number: Some(libc::SIGSYS),
+        alpha: Some(12),
+        mips: Some(12),
+        powerpc: Some(31),
+        sparc: Some(12),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Core"),
+        description: "Bad system call (SVr4); see also seccomp(2)",
+    },
+    Entry {
+        name: "SIGTERM",
+        number: Some(libc::SIGTERM),
+        alpha: Some(15),
+        mips: Some(15),
+        powerpc: Some(15),
+        sparc: Some(15),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "Termination signal",
+    },
+    Entry {
+        name: "SIGTRAP",
+        number: Some(libc::SIGTRAP),
+        alpha: Some(5),
+        mips: Some(5),
+        powerpc: Some(5),
+        sparc: Some(5),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Core"),
+        description: "Trace/breakpoint trap",
+    },
+    Entry {
+        name: "SIGTTIN",
+        number: Some(libc::SIGTTIN),
+        alpha: Some(21),
+        mips: Some(26),
+        powerpc: Some(21),
+        sparc: Some(21),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Stop"),
+        description: "Terminal input for background process",
+    },
+    Entry {
+        name: "SIGTTOU",
+        number: Some(libc::SIGTTOU),
+        alpha: Some(22),
+        mips: Some(27),
+        powerpc: Some(22),
+        sparc: Some(22),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Stop"),
+        description: "Terminal output for background process",
+    },
+    Entry {
+        name: "SIGUNUSED",
+        number: Some(libc::SIGSYS),
+        alpha: Some(12),
+        mips: Some(12),
+        powerpc: Some(31),
+        sparc: Some(12),
+        alias_of: Some("SIGSYS"),
+        standard: None,
+        action: Some("Core"),
+        description: "Synonymous with SIGSYS",
+    },
+    Entry {
+        name: "SIGURG",
+        number: Some(libc::SIGURG),
+        alpha: Some(16),
+        mips: Some(21),
+        powerpc: Some(23),
+        sparc: Some(16),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Ign"),
+        description: "Urgent condition on socket (4.2BSD)",
+    },
+    Entry {
+        name: "SIGUSR1",
+        number: Some(libc::SIGUSR1),
+        alpha: Some(30),
+        mips: Some(16),
+        powerpc: Some(10),
+        sparc: Some(30),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "User-defined signal 1",
+    },
+    Entry {
+        name: "SIGUSR2",
+        number: Some(libc::SIGUSR2),
+        alpha: Some(31),
+        mips: Some(17),
+        powerpc: Some(12),
+        sparc: Some(31),
+        alias_of: None,
+        standard: Some("P1990"),
+        action: Some("Term"),
+        description: "User-defined signal 2",
+    },
+    Entry {
+        name: "SIGVTALRM",
+        number: Some(libc::SIGVTALRM),
+        alpha: Some(26),
+        mips: Some(28),
+        powerpc: Some(26),
+        sparc: Some(26),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Term"),
+        description: "Virtual alarm clock (4.2BSD)",
+    },
+    Entry {
+        name: "SIGXCPU",
+        number: Some(libc::SIGXCPU),
+        alpha: Some(24),
+        mips: Some(30),
+        powerpc: Some(24),
+        sparc: Some(24),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Core"),
+        description: "CPU time limit exceeded (4.2BSD); see setrlimit(2)",
+    },
+    Entry {
+        name: "SIGXFSZ",
+        number: Some(libc::SIGXFSZ),
+        alpha: Some(25),
+        mips: Some(31),
+        powerpc: Some(25),
+        sparc: Some(25),
+        alias_of: None,
+        standard: Some("P2001"),
+        action: Some("Core"),
+        description: "File size limit exceeded (4.2BSD); see setrlimit(2)",
+    },
+    Entry {
+        name: "SIGWINCH",
+        number: Some(libc::SIGWINCH),
+        alpha: Some(28),
+        mips: Some(20),
+        powerpc: Some(28),
+        sparc: Some(28),
+        alias_of: None,
+        standard: None,
+        action: Some("Ign"),
+        description: "Window resize signal (4.3BSD, Sun)",
+    },
+];
@@ -0,0 +1,128 @@
+//! Decoding the errors and signals reported in the output of strace(1).
+
+/// What a line of strace output reports
+#[derive(Debug, PartialEq, Eq)]
+pub enum Event<'a> {
+    /// A system call failed (e.g. `openat(...) = -1 ENOENT (No such file or directory)`)
+    Error { syscall: &'a str, name: &'a str },
+    /// A signal was delivered (e.g. `--- SIGCHLD {si_signo=SIGCHLD, ...} ---`)
+    Signal { name: &'a str },
+    /// The process was killed (e.g. `+++ killed by SIGKILL +++`)
+    Killed { name: &'a str },
+}
+
+/// A line of strace output reporting an error or a signal
+#[derive(Debug, PartialEq, Eq)]
+pub struct Traced<'a> {
+    /// The process, when traced with `-f` (e.g. `[pid 1234] ...`)
+    pub pid: Option<u32>,
+    pub event: Event<'a>,
+    /// The byte offset just past the report, before the `-T` timing if any
+    pub end: usize,
+}
+
+/// Parses a line of strace output, returning `None` for the lines reporting neither an error nor a signal
+///
+/// Understands the pid prefixes of `-f` (both `[pid 1234]` and the `1234` of `-o`),
+/// the timestamps of `-t`, `-tt`, `-ttt` and `-r`, the timings of `-T`, and resumed system calls.
+pub fn parse(line: &str) -> Option<Traced<'_>> {
+    let (pid, rest) = strip_pid(line);
+    let rest = strip_timestamps(rest);
+    let content_end = line.trim_end().len();
+
+    if let Some(report) = rest.strip_prefix("--- ") {
+        let name = report
+            .split_whitespace()
+            .find(|word| is_name(word, "SIG"))?;
+        return Some(Traced {
+            pid,
+            event: Event::Signal { name },
+            end: content_end,
+        });
+    }
+    if let Some(report) = rest.strip_prefix("+++ killed by ") {
+        let name = report
+            .split_whitespace()
+            .next()
+            .filter(|word| is_name(word, "SIG"))?;
+        return Some(Traced {
+            pid,
+            event: Event::Killed { name },
+            end: content_end,
+        });
+    }
+
+    let syscall = match rest.strip_prefix("<... ") {
+        Some(resumed) => resumed.split_whitespace().next()?,
+        None => rest.split('(').next()?,
+    };
+    if syscall.is_empty()
+        || !syscall
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    {
+        return None;
+    }
+
+    // e.g. "-1 ENOENT (No such file or directory) <0.000012>" or "? ERESTARTSYS (...)"
+    let result_start = line.rfind(" = ")? + 3;
+    let result = &line[result_start..content_end];
+    let mut words = result.split(' ');
+    let _return_value = words.next()?;
+    let name = words.next().filter(|word| is_name(word, "E"))?;
+    let name_end = name.as_ptr() as usize - line.as_ptr() as usize + name.len();
+    let end = match line[name_end..content_end].strip_prefix(" (") {
+        Some(message) => message
+            .find(')')
+            .map_or(name_end, |close| name_end + 2 + close + 1),
+        None => name_end,
+    };
+    Some(Traced {
+        pid,
+        event: Event::Error { syscall, name },
+        end,
+    })
+}
+
+/// Splits off the `[pid 1234]` prefix of `-f`, or the bare pid strace writes with `-f -o`
+fn strip_pid(line: &str) -> (Option<u32>, &str) {
+    if let Some(rest) = line.strip_prefix("[pid ") {
+        if let Some((pid, rest)) = rest.trim_start().split_once(']') {
+            if let Ok(pid) = pid.parse() {
+                return (Some(pid), rest.trim_start());
+            }
+        }
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    match line[digits..].chars().next() {
+        Some(c) if digits > 0 && c.is_whitespace() => {
+            (line[..digits].parse().ok(), line[digits..].trim_start())
+        }
+        _ => (None, line),
+    }
+}
+
+/// Skips the timestamps of `-t`, `-tt`, `-ttt` and `-r` (e.g. "12:34:56.123456")
+fn strip_timestamps(mut line: &str) -> &str {
+    line = line.trim_start();
+    while let Some((word, rest)) = line.split_once(' ') {
+        if word.is_empty()
+            || !word
+                .bytes()
+                .all(|b| b.is_ascii_digit() || b == b':' || b == b'.')
+        {
+            break;
+        }
+        line = rest.trim_start();
+    }
+    line
+}
+
+/// Whether the word looks like a symbolic name with the prefix (e.g. "ENOENT" for "E")
+fn is_name(word: &str, prefix: &str) -> bool {
+    word.len() > prefix.len()
+        && word.starts_with(prefix)
+        && word
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+}
@@ -0,0 +1,38 @@
+//! Names of the system calls of the host architecture.
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "arm")]
+mod arm;
+#[cfg(target_arch = "x86")]
+mod x86;
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+#[cfg(target_arch = "aarch64")]
+use aarch64::TABLE;
+#[cfg(target_arch = "arm")]
+use arm::TABLE;
+#[cfg(target_arch = "x86")]
+use x86::TABLE;
+#[cfg(target_arch = "x86_64")]
+use x86_64::TABLE;
+
+/// The `AUDIT_ARCH_*` value of linux/audit.h for the host, which tells its system calls
+/// from those of the compat ABI (e.g. an i386 binary on x86_64)
+#[cfg(target_arch = "aarch64")]
+pub const AUDIT_ARCH: u32 = 0xc000_00b7;
+#[cfg(target_arch = "arm")]
+pub const AUDIT_ARCH: u32 = 0x4000_0028;
+#[cfg(target_arch = "x86")]
+pub const AUDIT_ARCH: u32 = 0x4000_0003;
+#[cfg(target_arch = "x86_64")]
+pub const AUDIT_ARCH: u32 = 0xc000_003e;
+
+/// Returns the name of the system call (e.g. "openat"), or `None` if the number is unknown
+pub fn name(number: i64) -> Option<&'static str> {
+    TABLE
+        .iter()
+        .find(|(_, nr)| *nr == number)
+        .map(|(name, _)| *name)
+}
@@ -0,0 +1,28 @@
+//! The exit codes of sysexits.h, which some programs use to tell why they failed.
+
+/// The codes with their names and the explanations of sysexits.h
+const TABLE: &[(i32, &str, &str)] = &[
+    (64, "EX_USAGE", "command line usage error"),
+    (65, "EX_DATAERR", "data format error"),
+    (66, "EX_NOINPUT", "cannot open input"),
+    (67, "EX_NOUSER", "addressee unknown"),
+    (68, "EX_NOHOST", "host name unknown"),
+    (69, "EX_UNAVAILABLE", "service unavailable"),
+    (70, "EX_SOFTWARE", "internal software error"),
+    (71, "EX_OSERR", "system error (e.g., can't fork)"),
+    (72, "EX_OSFILE", "critical OS file missing"),
+    (73, "EX_CANTCREAT", "can't create (user) output file"),
+    (74, "EX_IOERR", "input/output error"),
+    (75, "EX_TEMPFAIL", "temp failure; user is invited to retry"),
+    (76, "EX_PROTOCOL", "remote error in protocol"),
+    (77, "EX_NOPERM", "permission denied"),
+    (78, "EX_CONFIG", "configuration error"),
+];
+
+/// Returns the name and the explanation of an exit code (e.g. "EX_USAGE" for 64)
+pub fn lookup(code: i32) -> Option<(&'static str, &'static str)> {
+    TABLE
+        .iter()
+        .find(|(number, _, _)| *number == code)
+        .map(|(_, name, explanation)| (*name, *explanation))
+}
@@ -0,0 +1,332 @@
+//! A minimal strace(1): runs a command under ptrace(2) and records the system calls that fail.
+
+use libc::{c_int, c_long, c_void, pid_t};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+use std::ptr;
+
+// not in older versions of libc, nor in its musl flavour
+const PTRACE_GET_SYSCALL_INFO: c_int = 0x420e;
+const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+
+/// `struct ptrace_syscall_info` of linux/ptrace.h, its union flattened into `data`
+#[repr(C)]
+#[derive(Default)]
+struct SyscallInfo {
+    op: u8,
+    _reserved: [u8; 3],
+    /// The `AUDIT_ARCH_*` value of the system call; see [`crate::syscall::AUDIT_ARCH`]
+    arch: u32,
+    _instruction_pointer: u64,
+    _stack_pointer: u64,
+    /// entry: the number, then the 6 arguments; exit: the return value, then `is_error`
+    data: [u64; 8],
+}
+
+/// The system calls whose first argument is a path
+const PATH_FIRST: &[&str] = &[
+    "access",
+    "acct",
+    "chdir",
+    "chmod",
+    "chown",
+    "chown32",
+    "chroot",
+    "creat",
+    "execve",
+    "getxattr",
+    "lchown",
+    "lchown32",
+    "lgetxattr",
+    "link",
+    "listxattr",
+    "llistxattr",
+    "lstat",
+    "lstat64",
+    "mkdir",
+    "mknod",
+    "open",
+    "readlink",
+    "removexattr",
+    "rename",
+    "rmdir",
+    "setxattr",
+    "stat",
+    "stat64",
+    "statfs",
+    "statfs64",
+    "swapoff",
+    "swapon",
+    "symlink",
+    "truncate",
+    "truncate64",
+    "unlink",
+    "uselib",
+    "utime",
+    "utimes",
+];
+
+/// The system calls whose second argument is a path (mostly the `*at` variants)
+const PATH_SECOND: &[&str] = &[
+    "execveat",
+    "faccessat",
+    "faccessat2",
+    "fchmodat",
+    "fchmodat2",
+    "fchownat",
+    "fstatat64",
+    "futimesat",
+    "inotify_add_watch",
+    "linkat",
+    "mkdirat",
+    "mknodat",
+    "name_to_handle_at",
+    "newfstatat",
+    "openat",
+    "openat2",
+    "readlinkat",
+    "renameat",
+    "renameat2",
+    "statx",
+    "unlinkat",
+    "utimensat",
+];
+
+/// A system call that failed with an error, and how often it did
+pub struct Failure {
+    /// The number of the system call on its architecture
+    pub syscall: i64,
+    /// The `AUDIT_ARCH_*` value of the system call, which differs from the host one for compat binaries
+    pub arch: u32,
+    pub errno: c_int,
+    pub count: usize,
+    /// The most telling argument of the first failing call: its path if it takes one, else the first
+    pub sample: String,
+}
+
+impl Failure {
+    /// Returns the name of the system call, or `None` if it is unknown or from a compat binary
+    pub fn name(&self) -> Option<&'static str> {
+        Self::name_of(self.arch, self.syscall)
+    }
+
+    fn name_of(arch: u32, syscall: i64) -> Option<&'static str> {
+        if arch != crate::syscall::AUDIT_ARCH {
+            return None;
+        }
+        crate::syscall::name(syscall)
+    }
+}
+
+/// What happened while tracing a command
+pub struct Report {
+    /// The failures, in the order they first happened
+    pub failures: Vec<Failure>,
+    /// The wait status of the command, as returned by waitpid(2)
+    pub status: c_int,
+}
+
+/// Runs the command under ptrace(2), following its children and threads, until it exits
+///
+/// Needs Linux 5.3 or later for `PTRACE_GET_SYSCALL_INFO`.
+pub fn run(program: &str, args: &[String]) -> io::Result<Report> {
+    let mut command = Command::new(program);
+    command.args(args);
+    unsafe {
+        command.pre_exec(|| {
+            if libc::ptrace(
+                libc::PTRACE_TRACEME,
+                0,
+                ptr::null_mut::<c_void>(),
+                0 as c_long,
+            ) == -1
+            {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    let child = command.spawn()?;
+    let pid = child.id() as pid_t;
+
+    // the command stops with SIGTRAP once execve(2) has succeeded
+    wait(pid)?;
+    let options = libc::PTRACE_O_TRACESYSGOOD
+        | libc::PTRACE_O_EXITKILL
+        | libc::PTRACE_O_TRACECLONE
+        | libc::PTRACE_O_TRACEFORK
+        | libc::PTRACE_O_TRACEVFORK
+        | libc::PTRACE_O_TRACEEXEC;
+    ptrace(libc::PTRACE_SETOPTIONS as _, pid, options as c_long)?;
+    ptrace(libc::PTRACE_SYSCALL as _, pid, 0)?;
+
+    let mut failures: Vec<Failure> = Vec::new();
+    let mut entered: HashMap<pid_t, (u32, [u64; 7])> = HashMap::new();
+    let mut seen = vec![pid];
+    let mut status = 0;
+    loop {
+        let (tid, tid_status) = match wait(-1) {
+            Ok(waited) => waited,
+            Err(err) if err.raw_os_error() == Some(libc::ECHILD) => break,
+            Err(err) => return Err(err),
+        };
+        if libc::WIFEXITED(tid_status) || libc::WIFSIGNALED(tid_status) {
+            if tid == pid {
+                status = tid_status;
+            }
+            entered.remove(&tid);
+            continue;
+        }
+        if !libc::WIFSTOPPED(tid_status) {
+            continue;
+        }
+
+        let signal = libc::WSTOPSIG(tid_status);
+        let mut inject = 0;
+        if signal == libc::SIGTRAP | 0x80 {
+            let info = syscall_info(tid)?;
+            match info.op {
+                PTRACE_SYSCALL_INFO_ENTRY => {
+                    let mut entry = [0; 7];
+                    entry.copy_from_slice(&info.data[..7]);
+                    entered.insert(tid, (info.arch, entry));
+                }
+                PTRACE_SYSCALL_INFO_EXIT => {
+                    let is_error = info.data[1].to_ne_bytes()[0] != 0;
+                    if let (true, Some((arch, entry))) = (is_error, entered.remove(&tid)) {
+                        let errno = -(info.data[0] as i64) as c_int;
+                        record(&mut failures, tid, arch, entry, errno);
+                    }
+                }
+                _ => {}
+            }
+        } else if signal == libc::SIGTRAP && tid_status >> 16 != 0 {
+            // a fork, clone or exec event; the new tracees report themselves
+        } else if !seen.contains(&tid) {
+            // the SIGSTOP a new tracee starts with
+            seen.push(tid);
+        } else if !is_group_stop(tid, signal) {
+            inject = signal;
+        }
+
+        // the tracee may have been killed in the meantime
+        let _ = ptrace(libc::PTRACE_SYSCALL as _, tid, inject as c_long);
+    }
+    drop(child);
+    Ok(Report { failures, status })
+}
+
+/// Counts a failure, keeping the sample of the first one
+fn record(failures: &mut Vec<Failure>, tid: pid_t, arch: u32, entry: [u64; 7], errno: c_int) {
+    let syscall = entry[0] as i64;
+    let args = &entry[1..];
+    if let Some(failure) = failures.iter_mut().find(|failure| {
+        failure.syscall == syscall && failure.arch == arch && failure.errno == errno
+    }) {
+        failure.count += 1;
+        return;
+    }
+    let name = Failure::name_of(arch, syscall).unwrap_or("");
+    let path = if PATH_FIRST.contains(&name) {
+        Some(args[0])
+    } else if PATH_SECOND.contains(&name) {
+        Some(args[1])
+    } else {
+        None
+    };
+    // ints are passed zero-extended (e.g. the pid -1 of wait4 as 0xffffffff)
+    let first = match args[0] >> 32 {
+        0 => args[0] as u32 as i32 as i64,
+        _ => args[0] as i64,
+    };
+    let sample = match path.and_then(|address| read_string(tid, address)) {
+        Some(path) => format!("{:?}", path),
+        None if (-4096..65536).contains(&first) => first.to_string(),
+        None => format!("{:#x}", args[0]),
+    };
+    failures.push(Failure {
+        syscall,
+        arch,
+        errno,
+        count: 1,
+        sample,
+    });
+}
+
+/// Reads a NUL-terminated string of at most 256 bytes from the memory of the tracee
+fn read_string(tid: pid_t, address: u64) -> Option<String> {
+    let mem = File::open(format!("/proc/{}/mem", tid)).ok()?;
+    let mut buf = [0; 256];
+    let len = mem.read_at(&mut buf, address).ok()?;
+    let buf = &buf[..len];
+    let string = match buf.iter().position(|byte| *byte == 0) {
+        Some(nul) => String::from_utf8_lossy(&buf[..nul]).into_owned(),
+        None => format!("{}...", String::from_utf8_lossy(buf)),
+    };
+    Some(string)
+}
+
+/// Whether the stop is a group-stop rather than the delivery of a stopping signal,
+/// which PTRACE_GETSIGINFO tells apart by failing with EINVAL
+fn is_group_stop(tid: pid_t, signal: c_int) -> bool {
+    if !matches!(
+        signal,
+        libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU
+    ) {
+        return false;
+    }
+    let mut siginfo = std::mem::MaybeUninit::<libc::siginfo_t>::uninit();
+    let ret = unsafe {
+        libc::ptrace(
+            libc::PTRACE_GETSIGINFO,
+            tid,
+            ptr::null_mut::<c_void>(),
+            siginfo.as_mut_ptr(),
+        )
+    };
+    ret == -1
+}
+
+fn syscall_info(tid: pid_t) -> io::Result<SyscallInfo> {
+    let mut info = SyscallInfo::default();
+    let ret = unsafe {
+        libc::ptrace(
+            PTRACE_GET_SYSCALL_INFO as _,
+            tid,
+            std::mem::size_of::<SyscallInfo>(),
+            &mut info as *mut SyscallInfo,
+        )
+    };
+    if ret == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(info)
+}
+
+fn ptrace(request: c_int, tid: pid_t, data: c_long) -> io::Result<()> {
+    let ret = unsafe { libc::ptrace(request as _, tid, ptr::null_mut::<c_void>(), data) };
+    if ret == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Waits for any of the tracees (`-1`) or the given one, retrying on EINTR
+fn wait(tid: pid_t) -> io::Result<(pid_t, c_int)> {
+    loop {
+        let mut status = 0;
+        let waited = unsafe { libc::waitpid(tid, &mut status, libc::__WALL) };
+        if waited != -1 {
+            return Ok((waited, status));
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+//! Drives `lookup browse` through a pseudo-terminal.
+
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    os::fd::FromRawFd,
+    os::unix::process::CommandExt,
+    process::{Child, Command, Stdio},
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+const ROWS: usize = 30;
+const COLS: usize = 100;
+
+struct Browser {
+    child: Child,
+    input: File,
+    output: Receiver<Vec<u8>>,
+    received: Vec<u8>,
+}
+
+impl Browser {
+    fn spawn(args: &[&str]) -> Self {
+        let (mut master, mut slave) = (0, 0);
+        let size = libc::winsize {
+            ws_row: ROWS as u16,
+            ws_col: COLS as u16,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        let ret = unsafe {
+            libc::openpty(
+                &mut master,
+                &mut slave,
+                std::ptr::null_mut(),
+                std::ptr::null(),
+                &size,
+            )
+        };
+        assert_eq!(ret, 0, "openpty failed");
+        let (master, slave) = unsafe { (File::from_raw_fd(master), File::from_raw_fd(slave)) };
+
+        let child = Command::new(env!("CARGO_BIN_EXE_lookup"))
+            .arg("browse")
+            .args(args)
+            .stdin(slave.try_clone().unwrap())
+            .stdout(slave.try_clone().unwrap())
+            .stderr(slave)
+            .spawn()
+            .unwrap();
+
+        let (sender, output) = mpsc::channel();
+        let mut reader = master.try_clone().unwrap();
+        thread::spawn(move || {
+            let mut buf = [0; 4096];
+            while let Ok(n @ 1..) = reader.read(&mut buf) {
+                if sender.send(buf[..n].to_vec()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            child,
+            input: master,
+            output,
+            received: Vec::new(),
+        }
+    }
+
+    fn press(&mut self, keys: &str) {
+        self.input.write_all(keys.as_bytes()).unwrap();
+    }
+
+    /// Waits until the screen satisfies the condition
+    fn wait_until(&mut self, what: &str, cond: impl Fn(&str) -> bool) {
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            let screen = render(&String::from_utf8_lossy(&self.received));
+            if cond(&screen) {
+                return;
+            }
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            match self.output.recv_timeout(timeout) {
+                Ok(bytes) => self.received.extend(bytes),
+                Err(_) => panic!("timed out waiting for {}; the screen is:\n{}", what, screen),
+            }
+        }
+    }
+
+    fn wait_for(&mut self, text: &str) {
+        self.wait_until(text, |screen| screen.contains(text));
+    }
+
+    fn wait_for_exit(mut self) {
+        let deadline = Instant::now() + TIMEOUT;
+        while Instant::now() < deadline {
+            if let Some(status) = self.child.try_wait().unwrap() {
+                assert!(status.success());
+                return;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        self.child.kill().unwrap();
+        panic!("the browser did not exit");
+    }
+}
+
+/// Replays the output on a blank screen, supporting the escape sequences the browser emits
+fn render(output: &str) -> String {
+    let mut screen = vec![vec![' '; COLS]; ROWS];
+    let (mut row, mut col) = (0, 0);
+
+    let mut chars = output.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                let mut params = String::new();
+                let mut command = ' ';
+                for ch in chars.by_ref() {
+                    if ch.is_ascii_alphabetic() {
+                        command = ch;
+                        break;
+                    }
+                    params.push(ch);
+                }
+                let args: Vec<usize> = params
+                    .split(';')
+                    .map(|arg| arg.parse().unwrap_or(0))
+                    .collect();
+                match command {
+                    'H' => {
+                        row = args[0].max(1) - 1;
+                        col = args.get(1).copied().unwrap_or(1).max(1) - 1;
+                    }
+                    'J' if args[0] == 2 => screen = vec![vec![' '; COLS]; ROWS],
+                    'K' => screen[row.min(ROWS - 1)][col.min(COLS)..].fill(' '),
+                    _ => {}
+                }
+            }
+            '\x1b' => {
+                chars.next();
+            }
+            '\r' => col = 0,
+            '\n' => row += 1,
+            ch if !ch.is_control() => {
+                if row < ROWS && col < COLS {
+                    screen[row][col] = ch;
+                }
+                col += 1;
+            }
+            _ => {}
+        }
+    }
+
+    let lines: Vec<String> = screen.iter().map(|line| line.iter().collect()).collect();
+    lines.join("\n")
+}
+
+#[test]
+fn filter_and_show_details() {
+    let mut browser = Browser::spawn(&["errno"]);
+    browser.wait_for("E2BIG");
+
+    browser.press("emfile");
+    browser.wait_for("EMFILE");
+
+    browser.press("\r");
+    browser.wait_for("RLIMIT_NOFILE");
+
+    // close the details, clear the filter, then quit
+    browser.press("\x1b");
+    browser.wait_until("the details to close", |screen| {
+        !screen.contains("┌details")
+    });
+    browser.press("\x1b");
+    browser.wait_for("E2BIG");
+    browser.press("\x1b");
+    browser.wait_for_exit();
+}
+
+#[test]
+fn switch_tables() {
+    let mut browser = Browser::spawn(&["signal"]);
+    browser.wait_for("SIGABRT");
+
+    browser.press("\t");
+    browser.wait_for("NUL");
+
+    browser.press("\x03");
+    browser.wait_for_exit();
+}
+
+#[test]
+fn fail_without_a_terminal() {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_lookup"));
+    command
+        .arg("browse")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null());
+    // leave the controlling terminal of `cargo test`, if any
+    unsafe {
+        command.pre_exec(|| {
+            if libc::setsid() == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    let output = command.output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("browse: "), "{}", stderr);
+}
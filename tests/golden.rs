@@ -0,0 +1,375 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+//! Compares the output of the binaries against the files in `tests/golden`.
+//!
+//! Run with `UPDATE_GOLDEN=1` to regenerate the files after an intended change.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::{env, fs, path::Path};
+
+fn golden(bin: &str, case: &str, args: &[&str]) {
+    golden_with_input(bin, case, args, "");
+}
+
+/// Like [`golden`], feeding the input to the standard input of the binary
+fn golden_with_input(bin: &str, case: &str, args: &[&str], input: &str) {
+    let exe = match bin {
+        "ascii" => env!("CARGO_BIN_EXE_ascii"),
+        "signal" => env!("CARGO_BIN_EXE_signal"),
+        "errno" => env!("CARGO_BIN_EXE_errno"),
+        "lookup" => env!("CARGO_BIN_EXE_lookup"),
+        _ => unreachable!(),
+    };
+    let mut child = Command::new(exe)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "`{} {}` failed",
+        bin,
+        args.join(" ")
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(bin)
+        .join(format!("{}.txt", case));
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    assert_eq!(
+        actual,
+        expected,
+        "output of `{} {}` differs from {}",
+        bin,
+        args.join(" "),
+        path.display()
+    );
+}
+
+#[test]
+fn ascii_table() {
+    golden("ascii", "char", &["A"]);
+    golden("ascii", "numbers", &["0x42", "0b1000011", "0o104", "69"]);
+    golden("ascii", "caret", &["^["]);
+    golden("ascii", "digit", &["-d", "7"]);
+    golden("ascii", "unknown", &["0x80", "é"]);
+}
+
+#[test]
+fn ascii_simple() {
+    golden("ascii", "simple", &["--simple", "A", "0x2C", "^?", "0x80"]);
+}
+
+#[test]
+fn ascii_list() {
+    golden("ascii", "list", &["--list"]);
+    golden("ascii", "list-simple", &["--list", "--simple"]);
+}
+
+#[test]
+fn signal_table() {
+    golden("signal", "number", &["2"]);
+    golden("signal", "status", &["-s", "130"]);
+    golden("signal", "name", &["SIGINT"]);
+    golden("signal", "unknown", &["99", "SIGFOO"]);
+}
+
+#[test]
+fn signal_simple() {
+    golden(
+        "signal",
+        "simple",
+        &["--simple", "SIGCHLD", "SIGCLD", "SIGEMT", "99"],
+    );
+}
+
+#[test]
+fn signal_list() {
+    golden("signal", "list", &["--list"]);
+    golden("signal", "list-simple", &["--list", "--simple"]);
+}
+
+#[test]
+fn signal_json() {
+    golden("signal", "json", &["--json", "SIGCHLD", "SIGFOO"]);
+}
+
+#[test]
+fn signal_format() {
+    golden("signal", "format-tsv", &["--list", "--format", "tsv"]);
+}
+
+#[test]
+fn signal_arch() {
+    golden("signal", "arch-mips", &["--arch", "mips", "16", "SIGBUS"]);
+    golden(
+        "signal",
+        "all-arches",
+        &["--all-arches", "--list", "--simple"],
+    );
+}
+
+#[test]
+fn signal_wait_status() {
+    golden(
+        "signal",
+        "wait-status",
+        &[
+            "--wait-status",
+            "134",
+            "0x8b",
+            "35711",
+            "16384",
+            "0xffff",
+            "0x3057f",
+            "0x80137f",
+        ],
+    );
+}
+
+#[test]
+fn signal_realtime() {
+    golden(
+        "signal",
+        "realtime",
+        &["32", "34", "SIGRTMIN+3", "__SIGRTMIN+3", "SIGRTMAX-1"],
+    );
+    golden(
+        "signal",
+        "realtime-json",
+        &["--jsonl", "SIGRTMIN+20", "SIGINT"],
+    );
+    golden(
+        "signal",
+        "realtime-mips",
+        &[
+            "--arch",
+            "mips",
+            "SIGRTMIN+20",
+            "100",
+            "SIGRTMAX-1",
+            "__SIGRTMIN+3",
+            "128",
+        ],
+    );
+}
+
+#[test]
+fn signal_filter() {
+    golden("signal", "filter-core", &["--filter", "action=core"]);
+    golden(
+        "signal",
+        "filter-sync",
+        &[
+            "--filter",
+            "sync=yes",
+            "--filter",
+            "catchable=yes",
+            "--simple",
+        ],
+    );
+}
+
+#[test]
+fn signal_code() {
+    golden(
+        "signal",
+        "code",
+        &["--code", "SIGSEGV", "1", "0x80", "-6", "SEGV_PKUERR", "9"],
+    );
+    golden("signal", "code-list", &["--code", "SIGCLD", "--simple"]);
+}
+
+#[test]
+fn signal_mask() {
+    golden("signal", "mask", &["--mask", "0000000000004a03"]);
+    golden(
+        "signal",
+        "to-mask",
+        &["--to-mask", "SIGHUP", "2", "SIGUSR1"],
+    );
+}
+
+#[test]
+fn signal_os() {
+    golden(
+        "signal",
+        "os-macos",
+        &["--os", "macos", "30", "SIGINFO", "SIGPWR"],
+    );
+    golden(
+        "signal",
+        "os-freebsd",
+        &["--os", "freebsd", "--list", "--simple"],
+    );
+    golden("signal", "os-wasi", &["--os", "wasi", "--list", "--simple"]);
+}
+
+#[test]
+fn errno_table() {
+    golden("errno", "number", &["10"]);
+    golden("errno", "name", &["ECHILD"]);
+    golden("errno", "unknown", &["999", "EFOO"]);
+}
+
+#[test]
+fn errno_simple() {
+    golden(
+        "errno",
+        "simple",
+        &["--simple", "EAGAIN", "EWOULDBLOCK", "999"],
+    );
+}
+
+#[test]
+fn errno_list() {
+    golden("errno", "list", &["--list"]);
+    golden("errno", "list-simple", &["--list", "--simple"]);
+}
+
+#[test]
+fn errno_arch() {
+    golden(
+        "errno",
+        "arch-sparc",
+        &["--arch", "sparc", "--simple", "35", "78", "EDEADLOCK"],
+    );
+    golden(
+        "errno",
+        "all-arches",
+        &["--all-arches", "EAGAIN", "EDEADLOCK", "999"],
+    );
+}
+
+#[test]
+fn errno_json() {
+    golden("errno", "json", &["--json", "11", "999"]);
+}
+
+#[test]
+fn errno_format() {
+    // the description holds commas and quotes
+    golden("errno", "format-csv", &["--format", "csv", "ENODATA"]);
+}
+
+#[test]
+fn errno_search() {
+    golden(
+        "errno",
+        "search",
+        &["--simple", "--search", "open", "files"],
+    );
+}
+
+#[test]
+fn errno_os() {
+    golden(
+        "errno",
+        "os-macos",
+        &["--os", "macos", "35", "102", "EBADMACHO"],
+    );
+    golden(
+        "errno",
+        "os-illumos",
+        &["--os", "illumos", "--list", "--simple"],
+    );
+}
+
+/// A log mentioning errors and signals in the ways `--annotate` recognizes
+const LOG: &str = "\
+open: Too many open files (os error 24)
+read failed: errno=11, retrying
+Error: Os { code: 2, kind: NotFound, message: \"No such file or directory\" }
+write: ENOSPC
+worker 42 killed by signal 9
+child exited: exit status 137
+nothing to see here
+";
+
+/// A log whose numbers mean something else on MIPS (EDEADLK and SIGUSR1 there)
+const LOG_MIPS: &str = "\
+lock: errno=45
+worker 7 killed by signal 16
+";
+
+#[test]
+fn errno_annotate() {
+    golden_with_input("errno", "annotate", &["--annotate"], LOG);
+    golden_with_input(
+        "errno",
+        "annotate-mips",
+        &["--annotate", "--arch", "mips"],
+        LOG_MIPS,
+    );
+}
+
+#[test]
+fn signal_annotate() {
+    golden_with_input("signal", "annotate", &["--annotate"], LOG);
+    golden_with_input(
+        "signal",
+        "annotate-mips",
+        &["--annotate", "--arch", "mips"],
+        LOG_MIPS,
+    );
+}
+
+/// Output of `strace -f -T`, with a line of `-f -o -tt` and an interrupted system call
+const TRACE: &str = "\
+execve(\"/bin/cat\", [\"cat\", \"/nonexistent\"], 0x7ffd3 /* 20 vars */) = 0 <0.000210>
+[pid  4242] openat(AT_FDCWD, \"/nonexistent\", O_RDONLY) = -1 ENOENT (No such file or directory) <0.000012>
+[pid  4242] openat(AT_FDCWD, \"/etc/ld.so.preload\", O_RDONLY|O_CLOEXEC) = -1 ENOENT (No such file or directory) <0.000009>
+[pid  4243] read(3,  <unfinished ...>
+[pid  4242] write(1, \"a = b\", 5) = -1 EPIPE (Broken pipe) <0.000007>
+[pid  4242] --- SIGPIPE {si_signo=SIGPIPE, si_code=SI_USER, si_pid=4242, si_uid=1000} ---
+[pid  4243] <... read resumed>0x7ffd, 10) = ? ERESTARTSYS (To be restarted if SA_RESTART is set) <1.002>
+[pid  4243] --- SIGCHLD {si_signo=SIGCHLD, si_code=CLD_EXITED, si_pid=4244, si_uid=1000, si_status=0, si_utime=0, si_stime=0} ---
+[pid  4242] +++ killed by SIGPIPE +++
+4243  12:00:01.123456 access(\"/etc/x\", R_OK) = -1 EACCES (Permission denied) <0.000011>
++++ exited with 0 +++
+";
+
+/// Output of `strace` without `-f`, where the pids are not known
+const TRACE_SINGLE: &str = "\
+openat(AT_FDCWD, \"/nonexistent\", O_RDONLY) = -1 ENOENT (No such file or directory)
+--- SIGSEGV {si_signo=SIGSEGV, si_code=SEGV_MAPERR, si_addr=NULL} ---
++++ killed by SIGSEGV (core dumped) +++
+";
+
+#[test]
+fn strace() {
+    golden_with_input("lookup", "strace", &["strace"], TRACE);
+    golden_with_input("lookup", "strace-summary", &["strace", "--summary"], TRACE);
+    golden_with_input(
+        "lookup",
+        "strace-single",
+        &["strace", "--summary"],
+        TRACE_SINGLE,
+    );
+}
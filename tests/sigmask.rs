@@ -0,0 +1,89 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+use lookup_utils::sigmask::{self, Masks};
+use std::time::{Duration, Instant};
+
+const STATUS: &str = "\
+Name:\tdaemon
+State:\tS (sleeping)
+SigQ:\t1/63379
+SigPnd:\t0000000000000000
+ShdPnd:\t0000000000000200
+SigBlk:\t0000000000000200
+SigIgn:\t0000000000005000
+SigCgt:\t0000000180000002
+";
+
+#[test]
+fn masks_are_parsed_from_the_status() {
+    let masks = Masks::parse(STATUS).unwrap();
+    assert_eq!(masks.name, "daemon");
+    assert!(sigmask::numbers(masks.pending).is_empty());
+    assert_eq!(sigmask::numbers(masks.shared_pending), [libc::SIGUSR1]);
+    assert_eq!(sigmask::numbers(masks.blocked), [libc::SIGUSR1]);
+    assert_eq!(
+        sigmask::numbers(masks.ignored),
+        [libc::SIGPIPE, libc::SIGTERM]
+    );
+    assert_eq!(sigmask::numbers(masks.caught), [libc::SIGINT, 32, 33]);
+}
+
+#[test]
+fn masks_are_encoded_back() {
+    let mask = sigmask::parse("0x0000000180004a03").unwrap();
+    let numbers = sigmask::numbers(mask);
+    assert_eq!(sigmask::from_numbers(numbers), Some(mask));
+    assert_eq!(sigmask::format(mask), "0000000180004a03");
+    assert_eq!(sigmask::from_numbers([64]), Some(1 << 63));
+    assert_eq!(sigmask::from_numbers([1, 65]), None);
+}
+
+#[test]
+fn masks_of_this_process_can_be_read() {
+    let masks = Masks::read(std::process::id()).unwrap();
+    assert!(!masks.name.is_empty());
+    assert!(Masks::parse("Name:\tno masks\n").is_none());
+}
+
+#[test]
+fn who_lists_the_processes_ignoring_a_signal() {
+    let mut child = std::process::Command::new("sh")
+        .args(["-c", "trap '' USR2; exec sleep 10"])
+        .spawn()
+        .unwrap();
+    // wait for the trap to be set and sleep to be executed
+    let pid = child.id();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Masks::read(pid).map_or(true, |masks| masks.name != "sleep") {
+        if Instant::now() > deadline {
+            child.kill().unwrap();
+            child.wait().unwrap();
+            panic!("sh did not execute sleep within 5 seconds");
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_signal"))
+        .args(["--who", "SIGUSR2", "--ignored", "--simple"])
+        .output()
+        .unwrap();
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.starts_with(&format!("{} sleep - yes -", pid))),
+        "{}",
+        stdout
+    );
+}
@@ -0,0 +1,218 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+use lookup_utils::{arch::Arch, ascii, errno, os::Os, signal};
+use std::collections::HashSet;
+
+/// Asserts that every name appears once and every synonym points at a canonical entry
+fn assert_consistent(names: &[String], alias_of: impl Fn(&str) -> Option<String>) {
+    let mut seen = HashSet::new();
+    for name in names {
+        assert!(seen.insert(name), "duplicate entry {}", name);
+        if let Some(canonical) = alias_of(name) {
+            assert!(
+                names.contains(&canonical),
+                "{} is a synonym for unknown {}",
+                name,
+                canonical
+            );
+            assert_eq!(
+                alias_of(&canonical),
+                None,
+                "{} is a synonym for a synonym",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn every_error_is_described_and_numbered() {
+    for name in errno::list() {
+        let errdesc = errno::ErrnoDesc::from_name(&name).expect(&name);
+        assert_ne!(errdesc.manpages_description(), "Unknown error", "{}", name);
+
+        let number = errdesc.number().expect(&name);
+        let canonical = errno::ErrnoDesc::from_number(number).expect(&name);
+        assert_eq!(canonical.number(), Some(number), "{}", name);
+    }
+}
+
+#[test]
+fn every_signal_is_described_and_numbered() {
+    // not used on Linux
+    let unnumbered = ["SIGEMT", "SIGLOST"];
+
+    for name in signal::list() {
+        let sigdesc = signal::SignalDesc::from_name(&name).expect(&name);
+        assert_ne!(sigdesc.manpages_description(), "Unknown signal", "{}", name);
+        assert!(sigdesc.action().is_some(), "{}", name);
+
+        match sigdesc.number() {
+            Some(number) => {
+                let canonical = signal::SignalDesc::from_number(number).expect(&name);
+                assert_eq!(canonical.number(), Some(number), "{}", name);
+            }
+            None => assert!(unnumbered.contains(&name.as_str()), "{}", name),
+        }
+    }
+}
+
+#[test]
+fn error_table_has_no_duplicates_or_gaps() {
+    let names = errno::list();
+    assert_consistent(&names, |name| {
+        let errdesc = errno::ErrnoDesc::from_name(name).unwrap();
+        errdesc.alias_of().map(str::to_owned)
+    });
+
+    // not assigned on Linux (41, 58), or not documented in errno(3):
+    // ENOCSI, EBFONT, EADV, ESRMNT, EDOTDOT, ENOTNAM, ENAVAIL
+    let unused = [41, 58, 50, 59, 68, 69, 73, 118, 119];
+
+    let max = names
+        .iter()
+        .filter_map(|name| errno::ErrnoDesc::from_name(name).unwrap().number())
+        .max()
+        .unwrap();
+    for number in 1..=max {
+        let found = errno::ErrnoDesc::from_number(number);
+        assert_eq!(found.is_none(), unused.contains(&number), "{}", number);
+        if let Some(errdesc) = found {
+            assert_eq!(errdesc.alias_of(), None, "{}", number);
+        }
+    }
+}
+
+#[test]
+fn signal_table_has_no_duplicates_or_gaps() {
+    let names = signal::list();
+    assert_consistent(&names, |name| {
+        let sigdesc = signal::SignalDesc::from_name(name).unwrap();
+        sigdesc.alias_of().map(str::to_owned)
+    });
+
+    for number in 1..=31 {
+        let sigdesc = signal::SignalDesc::from_number(number).unwrap();
+        assert_eq!(sigdesc.alias_of(), None, "{}", number);
+    }
+}
+
+/// Asserts that the names sharing a number on an architecture are synonyms
+fn assert_synonyms(
+    arch: Arch,
+    name: &str,
+    alias_of: Option<&str>,
+    canonical: (&str, Option<&str>),
+) {
+    let (canonical, canonical_alias_of) = canonical;
+    assert!(
+        name == canonical || alias_of == Some(canonical) || alias_of == canonical_alias_of,
+        "{} and {} share a number on {}",
+        name,
+        canonical,
+        arch.name()
+    );
+}
+
+#[test]
+fn numbers_are_unique_on_every_architecture() {
+    for arch in Arch::ALL {
+        for name in errno::list() {
+            let errdesc = errno::ErrnoDesc::from_name(&name).unwrap().on(arch);
+            let number = errdesc.number().unwrap();
+            let canonical = errno::ErrnoDesc::from_number_on(arch, number).unwrap();
+            let canonical = (canonical.name(), canonical.alias_of());
+            assert_synonyms(arch, &name, errdesc.alias_of(), canonical);
+        }
+
+        for name in signal::list_on(arch) {
+            let sigdesc = signal::SignalDesc::from_name_on(arch, &name).unwrap();
+            if let Some(number) = sigdesc.number() {
+                let canonical = signal::SignalDesc::from_number_on(arch, number).unwrap();
+                let canonical = (canonical.name(), canonical.alias_of());
+                assert_synonyms(arch, &name, sigdesc.alias_of(), canonical);
+            }
+        }
+    }
+}
+
+#[test]
+fn signal_codes_are_unique() {
+    for arch in Arch::ALL {
+        for name in signal::list() {
+            let sigdesc = signal::SignalDesc::from_name(&name).unwrap().on(arch);
+            for code in sigdesc.codes() {
+                let found = sigdesc.code(code.value).unwrap();
+                assert_eq!(found.name, code.name, "{} on {}", name, arch.name());
+            }
+        }
+    }
+}
+
+#[test]
+fn other_systems_have_unique_described_names() {
+    for os in Os::ALL {
+        let names = errno::list_in(os);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), names.len());
+        for name in &names {
+            let errdesc = errno::ErrnoDesc::from_name_in(os, name).unwrap();
+            assert!(!errdesc.manpages_description().is_empty(), "{}", name);
+            assert!(errno::ErrnoDesc::from_number_in(os, errdesc.number().unwrap()).is_some());
+        }
+
+        let names = signal::list_in(os);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), names.len());
+        for name in &names {
+            let sigdesc = signal::SignalDesc::from_name_in(os, name).unwrap();
+            assert!(!sigdesc.manpages_description().is_empty(), "{}", name);
+            if let Some(number) = sigdesc.number() {
+                assert!(signal::SignalDesc::from_number_in(os, number).is_some());
+            }
+        }
+    }
+}
+
+#[test]
+fn every_ascii_character_is_displayed() {
+    for ch in ascii::list() {
+        let repr = ascii::display_repr(ch).unwrap();
+        if ch.is_ascii_graphic() && ch != '\\' {
+            assert_eq!(repr, ch.to_string());
+        }
+        assert_eq!(ascii::number_to_char(&(ch as u8).to_string()), Some(ch));
+    }
+    assert_eq!(ascii::display_repr('é'), None);
+}
+
+#[test]
+fn bad_caret_notations_are_rejected() {
+    assert_eq!(ascii::caret_notation("^["), Some('\x1b'));
+    assert_eq!(ascii::caret_notation("^?"), Some('\x7f'));
+    for text in ["", "A", "^", "^a", "^AB", "^é"] {
+        assert_eq!(ascii::caret_notation(text), None, "{:?}", text);
+    }
+}
+
+#[test]
+fn other_systems_have_their_own_default_actions() {
+    for os in [Os::Freebsd, Os::Macos, Os::Openbsd] {
+        let sigio = signal::SignalDesc::from_name_in(os, "SIGIO").unwrap();
+        assert_eq!(sigio.action(), Some("Ign"), "SIGIO on {:?}", os);
+    }
+    let sigpwr = signal::SignalDesc::from_name_in(Os::Illumos, "SIGPWR").unwrap();
+    assert_eq!(sigpwr.action(), Some("Ign"));
+    for os in Os::ALL {
+        for name in signal::list_in(os) {
+            let sigdesc = signal::SignalDesc::from_name_in(os, &name).unwrap();
+            assert!(sigdesc.action().is_some(), "{} on {:?}", name, os);
+        }
+    }
+}
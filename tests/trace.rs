@@ -0,0 +1,34 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+use lookup_utils::{syscall, trace};
+
+#[test]
+fn failing_mkdir_is_recorded_with_its_path() {
+    let path = "/nonexistent/lookup-utils";
+    let report = match trace::run("mkdir", &[path.to_owned()]) {
+        Ok(report) => report,
+        // e.g. in a container whose seccomp profile forbids ptrace(2)
+        Err(err) if err.raw_os_error() == Some(libc::EPERM) => return,
+        Err(err) => panic!("cannot trace mkdir: {}", err),
+    };
+    assert!(libc::WIFEXITED(report.status));
+    assert_eq!(libc::WEXITSTATUS(report.status), 1);
+
+    let failure = report
+        .failures
+        .iter()
+        .find(|failure| failure.sample == format!("{:?}", path))
+        .expect("the mkdir of the path is recorded");
+    assert_eq!(failure.errno, libc::ENOENT);
+    assert_eq!(failure.arch, syscall::AUDIT_ARCH);
+    let name = failure.name().unwrap();
+    assert!(name.starts_with("mkdir"), "{}", name);
+}
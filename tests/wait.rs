@@ -0,0 +1,67 @@
+#![cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64"
+    )
+))]
+
+//! Checks how `signal --run` explains the end of a command, and that it exits like the command,
+//! and that `signal --wait-status` fails on invalid statuses.
+
+use std::process::Command;
+
+/// Runs `signal` with the arguments, returning its exit code and standard error
+fn signal(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_signal"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn run_explains_exit_codes() {
+    let (code, stderr) = signal(&["--run", "--", "sh", "-c", "exit 64"]);
+    assert_eq!(code, Some(64));
+    assert_eq!(
+        stderr,
+        "sh exited with status 64 (EX_USAGE: command line usage error)\n"
+    );
+
+    let (code, stderr) = signal(&["--run", "--", "sh", "-c", "exit 137"]);
+    assert_eq!(code, Some(137));
+    assert!(
+        stderr.contains("killed by SIGKILL (9): Kill signal"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn run_explains_deaths_by_signal() {
+    let (code, stderr) = signal(&["--run", "--", "sh", "-c", "kill -TERM $$"]);
+    assert_eq!(code, Some(143));
+    assert_eq!(
+        stderr,
+        "sh killed by SIGTERM (15): Termination signal; no core dumped\n"
+    );
+}
+
+#[test]
+fn run_reports_missing_commands_like_shells() {
+    let (code, _) = signal(&["--run", "--", "/nonexistent/lookup-utils"]);
+    assert_eq!(code, Some(127));
+}
+
+#[test]
+fn invalid_wait_statuses_fail() {
+    let (code, stderr) = signal(&["--wait-status", "abc", "0X8B"]);
+    assert_eq!(code, Some(1));
+    assert_eq!(stderr, "signal: invalid wait status 'abc'\n");
+}
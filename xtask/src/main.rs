@@ -0,0 +1,352 @@
+//! Development tasks of lookup-utils, run with `cargo xtask <task>`.
+
+mod roff;
+#[cfg(test)]
+mod tests;
+
+use clap::Parser;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Parser)]
+#[clap(name = "xtask")]
+enum Task {
+    /// Regenerate src/errno/table.rs and src/signal/table.rs from the man-pages sources
+    Tables {
+        /// Path to a checkout of https://git.kernel.org/pub/scm/docs/man-pages/man-pages.git
+        man_pages: PathBuf,
+    },
+}
+
+fn main() {
+    match Task::parse() {
+        Task::Tables { man_pages } => tables(&man_pages),
+    }
+}
+
+/// An entry as documented in the man page, its fields rendered as Rust expressions
+struct Row {
+    name: String,
+    fields: HashMap<&'static str, String>,
+}
+
+/// The fields of an errno entry, in order
+const ERRNO_FIELDS: &[&str] = &[
+    "number",
+    "alpha",
+    "mips",
+    "powerpc",
+    "sparc",
+    "alias_of",
+    "description",
+];
+
+/// The fields of a signal entry, in order
+const SIGNAL_FIELDS: &[&str] = &[
+    "number",
+    "alpha",
+    "mips",
+    "powerpc",
+    "sparc",
+    "alias_of",
+    "standard",
+    "action",
+    "description",
+];
+
+/// The fields of the entries of a previously generated table, by name and field
+type Kept = HashMap<(String, &'static str), String>;
+
+fn tables(man_pages: &Path) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let path = root.join("src/errno/table.rs");
+    let kept = kept_fields(&path);
+    let errno_3 = read_page(man_pages, "man3/errno.3");
+    let rows = errno_rows(&errno_3);
+    write_table(
+        &path,
+        &version(&errno_3),
+        ERRNO_FIELDS,
+        &rows,
+        &kept,
+        |field, name| match field {
+            "alias_of" => "None".to_owned(),
+            _ => format!("libc::{}", name),
+        },
+    );
+
+    let path = root.join("src/signal/table.rs");
+    let kept = kept_fields(&path);
+    let signal_7 = read_page(man_pages, "man7/signal.7");
+    let rows = signal_rows(&signal_7, &kept);
+    write_table(
+        &path,
+        &version(&signal_7),
+        SIGNAL_FIELDS,
+        &rows,
+        &kept,
+        |field, name| match field {
+            "alias_of" => "None".to_owned(),
+            _ => format!("Some(libc::{})", name),
+        },
+    );
+}
+
+/// Reads a page, either from `<checkout>/man3/errno.3` or `<checkout>/man/man3/errno.3` (since 6.05)
+fn read_page(man_pages: &Path, page: &str) -> String {
+    let candidates = [man_pages.join(page), man_pages.join("man").join(page)];
+    for path in &candidates {
+        if let Ok(source) = fs::read_to_string(path) {
+            return source;
+        }
+    }
+    eprintln!("xtask: cannot read {}", candidates[0].display());
+    std::process::exit(1);
+}
+
+/// Returns the release named in the `.TH` line (e.g. "Linux man-pages 6.01")
+fn version(source: &str) -> String {
+    source
+        .lines()
+        .find(|line| line.starts_with(".TH "))
+        .and_then(|line| line.split('"').nth(1))
+        .unwrap_or("Linux man-pages")
+        .to_owned()
+}
+
+/// Parses the "List of error names" section of errno(3): a `.TP` paragraph per name
+fn errno_rows(source: &str) -> Vec<Row> {
+    let lines = source
+        .lines()
+        .skip_while(|line| !line.starts_with("Below is a list"))
+        .skip(1);
+
+    let mut entries: Vec<(String, String)> = Vec::new();
+    let mut tagged = false;
+    for line in lines {
+        if line.starts_with(".SH") || line.starts_with(".SS") {
+            break;
+        }
+        if line.starts_with(".TP") {
+            tagged = true;
+            continue;
+        }
+        let text = match roff::line(line) {
+            Some(text) => text,
+            None => continue,
+        };
+        if tagged {
+            tagged = false;
+            entries.push((text.trim().to_owned(), String::new()));
+        } else if let Some((_, description)) = entries.last_mut() {
+            description.push(' ');
+            description.push_str(&text);
+        }
+    }
+
+    entries
+        .into_iter()
+        .map(|(name, description)| Row {
+            name,
+            fields: HashMap::from([("description", string(&description))]),
+        })
+        .collect()
+}
+
+/// Parses the tables of the "Standard signals" and "Signal numbering" sections of signal(7)
+fn signal_rows(source: &str, kept: &Kept) -> Vec<Row> {
+    let mut rows: Vec<Row> = Vec::new();
+    for cells in tbl_rows(source, ".SS Standard signals") {
+        let comment = cells.last().cloned().unwrap_or_default();
+        match rows.last_mut() {
+            // continuation of the comment of the previous row
+            Some(row) if cells[0].is_empty() => {
+                let description = row.fields.get_mut("description").unwrap();
+                description.push(' ');
+                description.push_str(&comment);
+            }
+            _ => {
+                let optional = |i: usize| match cells.get(i).map(String::as_str) {
+                    None | Some("") | Some("-") => "None".to_owned(),
+                    Some(value) => format!("Some({:?})", value),
+                };
+                rows.push(Row {
+                    name: cells[0].clone(),
+                    fields: HashMap::from([
+                        ("standard", optional(1)),
+                        ("action", optional(2)),
+                        ("description", comment),
+                    ]),
+                });
+            }
+        }
+    }
+    for row in &mut rows {
+        let description = row.fields["description"].clone();
+        row.fields.insert("description", string(&description));
+    }
+
+    // columns: x86/ARM and most others, Alpha/SPARC, MIPS, PARISC, notes
+    let mut numbers: HashMap<String, [String; 4]> = HashMap::new();
+    let mut same_as = Vec::new();
+    for cells in tbl_rows(source, ".SS Signal numbering for standard signals").skip(1) {
+        let cell = |i: usize| cells.get(i).map(|cell| cell.trim()).unwrap_or("");
+        if let Some(other) = cell(5).strip_prefix("Same as ") {
+            same_as.push((cell(0).to_owned(), other.to_owned()));
+            continue;
+        }
+        let (alpha, sparc) = cell(2).split_once('/').unwrap_or((cell(2), cell(2)));
+        let arch_numbers = [alpha, cell(3), cell(1), sparc].map(|number| match number {
+            "" | "-" => "None".to_owned(),
+            number => format!("Some({})", number),
+        });
+        numbers.insert(cell(0).to_owned(), arch_numbers);
+    }
+    for (name, other) in same_as {
+        if let Some(arch_numbers) = numbers.get(&other).cloned() {
+            numbers.insert(name, arch_numbers);
+        }
+    }
+    // synonyms also stand for the signal where the kernel does not define them (e.g. SIGCLD on x86)
+    for row in &rows {
+        let target = kept
+            .get(&(row.name.clone(), "alias_of"))
+            .and_then(|alias_of| alias_of.strip_prefix("Some(\"")?.strip_suffix("\")"));
+        let target_numbers = target.and_then(|target| numbers.get(target)).cloned();
+        if let (Some(target_numbers), Some(own)) = (target_numbers, numbers.get_mut(&row.name)) {
+            for (own, target) in own.iter_mut().zip(target_numbers) {
+                if own == "None" {
+                    *own = target;
+                }
+            }
+        }
+    }
+    for row in &mut rows {
+        if let Some(arch_numbers) = numbers.remove(&row.name) {
+            for (field, number) in ["alpha", "mips", "powerpc", "sparc"]
+                .iter()
+                .zip(arch_numbers)
+            {
+                row.fields.insert(field, number);
+            }
+        }
+    }
+    rows
+}
+
+/// Returns the cells of the rows of the first table after the heading, without the column formats
+fn tbl_rows<'a>(source: &'a str, heading: &str) -> impl Iterator<Item = Vec<String>> + 'a {
+    let heading = heading.to_owned();
+    source
+        .lines()
+        .skip_while(move |line| !line.starts_with(&heading))
+        .skip_while(|line| !line.starts_with(".TS"))
+        .skip(1)
+        // the column formats end with a period, then comes the heading
+        .skip_while(|line| !line.ends_with('.'))
+        .skip(2)
+        .take_while(|line| !line.starts_with(".TE"))
+        .map(|line| line.split('\t').map(roff::text).collect())
+}
+
+/// Renders a description as a Rust string literal
+fn string(text: &str) -> String {
+    format!(
+        "{:?}",
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    )
+}
+
+/// Writes a table, keeping the fields of the existing entries that the man page does not provide
+fn write_table(
+    path: &Path,
+    version: &str,
+    fields: &[&str],
+    rows: &[Row],
+    kept: &Kept,
+    default: impl Fn(&str, &str) -> String,
+) {
+    if rows.is_empty() {
+        eprintln!("xtask: no entries found for {}", path.display());
+        std::process::exit(1);
+    }
+    let kept_names: Vec<String> = fields
+        .iter()
+        .filter(|field| !rows[0].fields.contains_key(*field))
+        .map(|field| format!("`{}`", field))
+        .collect();
+    let mut out = format!(
+        "// NOTE: generated by `cargo xtask tables` from {}\n\
+         // {} are kept when regenerating,\n\
+         // the other fields come from the man page\n\
+         \n\
+         use super::Entry;\n\
+         \n\
+         pub(super) static TABLE: &[Entry] = &[\n",
+        version,
+        match kept_names.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+            None => "no fields".to_owned(),
+        },
+    );
+    for row in rows {
+        writeln!(out, "    Entry {{").unwrap();
+        writeln!(out, "        name: {:?},", row.name).unwrap();
+        for field in fields {
+            let value = match (row.fields.get(field), kept.get(&(row.name.clone(), *field))) {
+                (Some(value), _) | (None, Some(value)) => value.clone(),
+                (None, None) => {
+                    eprintln!("xtask: check the {} of new entry {}", field, row.name);
+                    default(field, &row.name)
+                }
+            };
+            writeln!(out, "        {}: {},", field, value).unwrap();
+        }
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    for name in removed(rows, kept) {
+        eprintln!("xtask: removed entry {}", name);
+    }
+    fs::write(path, out).unwrap_or_else(|err| {
+        eprintln!("xtask: cannot write {}: {}", path.display(), err);
+        std::process::exit(1);
+    });
+    println!("{}: {} entries", path.display(), rows.len());
+}
+
+/// Returns the names of the entries of the previous table that the man page no longer has
+fn removed<'a>(rows: &[Row], kept: &'a Kept) -> BTreeSet<&'a str> {
+    kept.keys()
+        .map(|(name, _)| name.as_str())
+        .filter(|name| !rows.iter().any(|row| row.name == *name))
+        .collect()
+}
+
+/// Collects the source of each field of the entries of a generated table
+fn kept_fields(path: &Path) -> Kept {
+    let table = fs::read_to_string(path).unwrap_or_default();
+    let mut kept = HashMap::new();
+    let mut name = String::new();
+    for line in table.lines().map(str::trim) {
+        let (field, value) = match line.split_once(": ") {
+            Some((field, value)) if value.ends_with(',') => (field, &value[..value.len() - 1]),
+            _ => continue,
+        };
+        if field == "name" {
+            name = value.trim_matches('"').to_owned();
+        } else if let Some(field) = ERRNO_FIELDS
+            .iter()
+            .chain(SIGNAL_FIELDS)
+            .find(|f| **f == field)
+        {
+            kept.insert((name.clone(), *field), value.to_owned());
+        }
+    }
+    kept
+}
@@ -0,0 +1,101 @@
+//! Just enough of roff to turn the man-pages sources into plain text.
+
+/// Renders a source line as plain text, or returns `None` for comments and requests without text
+pub fn line(line: &str) -> Option<String> {
+    if line.starts_with(".\\\"") || line.starts_with("'\\\"") {
+        return None;
+    }
+    let request = match line.strip_prefix('.') {
+        Some(request) => request,
+        None => return Some(text(line)),
+    };
+    let (name, rest) = request.split_once(' ').unwrap_or((request, ""));
+    let args: Vec<String> = args(rest).iter().map(|arg| text(arg)).collect();
+    match name {
+        "B" | "I" | "SM" | "SB" => Some(args.join(" ")),
+        // alternating fonts, no space between the arguments
+        "BR" | "RB" | "IR" | "RI" | "BI" | "IB" => Some(args.concat()),
+        _ => None,
+    }
+}
+
+/// Splits the arguments of a request, honoring double quotes
+fn args(rest: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut chars = rest.chars().peekable();
+    loop {
+        while chars.next_if_eq(&' ').is_some() {}
+        let mut arg = String::new();
+        match chars.peek() {
+            None => break,
+            Some('"') => {
+                chars.next();
+                while let Some(ch) = chars.next() {
+                    match ch {
+                        '"' if chars.next_if_eq(&'"').is_some() => arg.push('"'),
+                        '"' => break,
+                        _ => arg.push(ch),
+                    }
+                }
+            }
+            Some(_) => {
+                while let Some(ch) = chars.next_if(|ch| *ch != ' ') {
+                    arg.push(ch);
+                }
+            }
+        }
+        args.push(arg);
+    }
+    args
+}
+
+/// Resolves the escape sequences of a piece of text
+pub fn text(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            // font changes: \fB, \fP, \f[CR]
+            Some('f') => {
+                let font = chars.next();
+                if font == Some('[') {
+                    chars.by_ref().take_while(|ch| *ch != ']').for_each(drop);
+                }
+            }
+            Some('(') => {
+                let name: String = chars.by_ref().take(2).collect();
+                out.push_str(special(&name));
+            }
+            Some('[') => {
+                let name: String = chars.by_ref().take_while(|ch| *ch != ']').collect();
+                out.push_str(special(&name));
+            }
+            Some('e') => out.push('\\'),
+            Some('-') => out.push('-'),
+            // unbreakable and digit-width spaces
+            Some('~') | Some(' ') | Some('0') => out.push(' '),
+            Some('&') | Some('%') => {}
+            // comment until the end of the line
+            Some('"') => break,
+            Some(ch) => out.push(ch),
+            None => {}
+        }
+    }
+    out
+}
+
+fn special(name: &str) -> &'static str {
+    match name {
+        "aq" => "'",
+        "dq" | "lq" | "rq" => "\"",
+        "rs" => "\\",
+        "em" => "\u{2014}",
+        "en" => "\u{2013}",
+        "hy" => "-",
+        _ => "",
+    }
+}
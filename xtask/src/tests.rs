@@ -0,0 +1,96 @@
+//! Checks the man-pages parser against pages trimmed from the release the tables were generated from.
+
+use super::*;
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// A checkout of the man-pages with only the rows the parser has to handle
+fn man_pages() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/man-pages")
+}
+
+fn names(rows: &[Row]) -> Vec<&str> {
+    rows.iter().map(|row| row.name.as_str()).collect()
+}
+
+/// Asserts that every field parsed from the man page is the one of the checked-in table
+fn assert_unchanged(rows: &[Row], table: &Kept) {
+    for row in rows {
+        for (field, value) in &row.fields {
+            assert_eq!(
+                table.get(&(row.name.clone(), *field)),
+                Some(value),
+                "{} of {}",
+                field,
+                row.name
+            );
+        }
+    }
+}
+
+#[test]
+fn errno_table_regenerates_unchanged() {
+    let errno_3 = read_page(&man_pages(), "man3/errno.3");
+    assert_eq!(version(&errno_3), "Linux man-pages 6.01");
+
+    let rows = errno_rows(&errno_3);
+    assert_eq!(
+        names(&rows),
+        [
+            "E2BIG",
+            "EACCES",
+            "EADDRINUSE",
+            "EADDRNOTAVAIL",
+            "EAFNOSUPPORT",
+            "EAGAIN"
+        ]
+    );
+    // a description spread over several lines and requests
+    assert_eq!(
+        rows[5].fields["description"],
+        "\"Resource temporarily unavailable (may be the same value as EWOULDBLOCK) (POSIX.1-2001).\""
+    );
+    assert_unchanged(&rows, &kept_fields(&root().join("src/errno/table.rs")));
+}
+
+#[test]
+fn signal_table_regenerates_unchanged() {
+    let signal_7 = read_page(&man_pages(), "man7/signal.7");
+    assert_eq!(version(&signal_7), "Linux man-pages 6.01");
+
+    let table = kept_fields(&root().join("src/signal/table.rs"));
+    let rows = signal_rows(&signal_7, &table);
+    assert_eq!(
+        names(&rows),
+        [
+            "SIGABRT", "SIGCHLD", "SIGCLD", "SIGHUP", "SIGINFO", "SIGIO", "SIGPOLL", "SIGPWR",
+            "SIGXCPU"
+        ]
+    );
+    let sighup = &rows[3];
+    // the comment continues on the next row
+    assert_eq!(
+        sighup.fields["description"],
+        "\"Hangup detected on controlling terminal or death of controlling process\""
+    );
+    // "\01" is a 1 padded to the width of two digits
+    assert_eq!(sighup.fields["alpha"], "Some(1)");
+    assert_eq!(rows[4].fields["action"], "None");
+    assert_unchanged(&rows, &table);
+}
+
+#[test]
+fn removed_entries_are_named_once() {
+    let kept = Kept::from([
+        (("EGONE".to_owned(), "alpha"), "1".to_owned()),
+        (("EGONE".to_owned(), "mips"), "1".to_owned()),
+        (("EKEPT".to_owned(), "alpha"), "2".to_owned()),
+    ]);
+    let rows = [Row {
+        name: "EKEPT".to_owned(),
+        fields: HashMap::new(),
+    }];
+    assert_eq!(removed(&rows, &kept), BTreeSet::from(["EGONE"]));
+}